@@ -44,4 +44,148 @@ pub enum TokenError {
     MissingReUpPercentage,
     #[msg("Unexpected ReUp percentage")]
     UnexpectedReUpPercentage,
+    #[msg("ReUp cooldown has not elapsed")]
+    ReUpCooldown,
+    #[msg("Invalid ReUp policy")]
+    InvalidReUpPolicy,
+    #[msg("Unexpected ReUp policy")]
+    UnexpectedReUpPolicy,
+    #[msg("Missing equation type")]
+    MissingEquationType,
+    #[msg("Missing pause type")]
+    MissingPauseType,
+    #[msg("Missing equation params")]
+    MissingEquationParams,
+    #[msg("Equation params are invalid for the equation type")]
+    InvalidEquationParams,
+    #[msg("Undefined variable in balance equation")]
+    UndefinedVariable,
+    #[msg("Balance equation could not be parsed")]
+    ExpressionParseError,
+    #[msg("Division by zero in balance equation")]
+    DivisionByZero,
+    #[msg("Balance equation produced a non-finite result")]
+    NonFiniteBalance,
+    #[msg("Balance equation produced a negative result")]
+    NegativeBalance,
+    #[msg("Invalid subscription plan")]
+    InvalidSubscriptionPlan,
+    #[msg("Subscription plan mismatch")]
+    SubscriptionPlanMismatch,
+    #[msg("Subscription plan renewal limit reached")]
+    MaxRenewalsReached,
+    #[msg("Subscription has been cancelled")]
+    SubscriptionCancelled,
+    #[msg("Subscription period has not elapsed")]
+    SubscriptionPeriodNotElapsed,
+    #[msg("Subscription is not active on this account")]
+    SubscriptionNotActive,
+    #[msg("Amount exceeds the vested balance")]
+    InsufficientVestedFunds,
+    #[msg("Invalid equation schedule")]
+    InvalidSchedule,
+    #[msg("Mint has an equation schedule that was not provided")]
+    MissingSchedule,
+    #[msg("Invalid tier table")]
+    InvalidTierTable,
+    #[msg("Account has a tier but the mint's tier table was not provided")]
+    MissingTierTable,
+    #[msg("Tier does not exist in the mint's tier table")]
+    UnknownTier,
+    #[msg("Mint has an exemption registry that was not provided")]
+    MissingExemptionRegistry,
+    #[msg("Owner account is required to check owner program exemptions")]
+    MissingExemptionOwner,
+    #[msg("Exemption registry is full")]
+    ExemptionRegistryFull,
+    #[msg("Account is already exempt")]
+    AlreadyExempt,
+    #[msg("Account is not exempt")]
+    NotExempt,
+    #[msg("Invalid transfer fee")]
+    InvalidTransferFee,
+    #[msg("Transfer fees are not enabled on this mint")]
+    TransferFeeNotEnabled,
+    #[msg("Invalid fee authority")]
+    InvalidFeeAuthority,
+    #[msg("Mint withholds fees into a fee vault that was not provided")]
+    MissingFeeVault,
+    #[msg("Invalid emission schedule")]
+    InvalidEmissionSchedule,
+    #[msg("Mint would exceed its maximum supply")]
+    MaxSupplyExceeded,
+    #[msg("Amount exceeds the unlocked emission allowance")]
+    EmissionAllowanceExceeded,
+    #[msg("Mint has no emission schedule")]
+    MissingEmissionSchedule,
+    #[msg("No emission allowance is unlocked")]
+    NothingToEmit,
+    #[msg("Token account is not the emission treasury")]
+    TreasuryMismatch,
+    #[msg("Mint is paused")]
+    MintPaused,
+    #[msg("Mint is not paused")]
+    MintNotPaused,
+    #[msg("Invalid pause authority")]
+    InvalidPauseAuthority,
+    #[msg("Decimals do not match the mint")]
+    MintDecimalsMismatch,
+    #[msg("Batch needs one recipient and decay pool per amount")]
+    InvalidBatch,
+    #[msg("Decay pool does not belong to the token account")]
+    InvalidDecayPool,
+    #[msg("Invalid distributor")]
+    InvalidDistributor,
+    #[msg("Invalid merkle proof")]
+    InvalidProof,
+    #[msg("Claim window has closed")]
+    ClaimWindowClosed,
+    #[msg("Claim window is still open")]
+    ClaimWindowOpen,
+    #[msg("Distributor has nothing left to distribute")]
+    DistributorExhausted,
+    #[msg("Invalid snapshot")]
+    InvalidSnapshot,
+    #[msg("Snapshot is not accepting records")]
+    SnapshotWindowClosed,
+    #[msg("Account was settled after the snapshot time")]
+    SnapshotBalanceUnavailable,
+    #[msg("Invalid escrow")]
+    InvalidEscrow,
+    #[msg("Escrow is still locked")]
+    EscrowLocked,
+    #[msg("Escrow has already been released or refunded")]
+    EscrowSettled,
+    #[msg("Signer cannot settle this escrow")]
+    InvalidEscrowAuthority,
+    #[msg("Nothing is staked")]
+    NothingStaked,
+    #[msg("Insufficient staked amount")]
+    InsufficientStake,
+    #[msg("No stake rewards to claim")]
+    NoStakeRewards,
+    #[msg("Invalid stake position")]
+    InvalidStakePosition,
+    #[msg("Decay pool is empty")]
+    NothingToHarvest,
+    #[msg("Invalid stream")]
+    InvalidStream,
+    #[msg("Nothing has streamed since the last withdrawal")]
+    NothingToWithdraw,
+    #[msg("Stream has already ended")]
+    StreamEnded,
+    #[msg("Only the sender or recipient can cancel a stream")]
+    InvalidStreamAuthority,
+    #[msg("Every token account an exemption change affects must be passed to be settled")]
+    UnsettledExemption,
+    #[msg("The owner has not opted this account into harvesting")]
+    NotHarvestable,
+    #[msg("Account is already a harvest source")]
+    AlreadyHarvestable,
+    #[msg("Stake pool has no room for more harvest sources")]
+    HarvestSourcesFull,
+    #[msg("Reward duration must be positive")]
+    InvalidRewardDuration,
+    #[msg("Program vaults only move through their own instructions")]
+    VaultAccount,
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use anchor_lang::event;
 use anchor_lang::prelude::*;
+use crate::state::{ChronoEquationType, EscrowDecayMode};
 
 #[event]
 pub struct MintToEvent {
@@ -32,4 +33,207 @@ pub struct ReUpEvent {
     pub amount: u64,
     pub new_balance: u64,
     pub decay_pool_balance: u64,
-}
\ No newline at end of file
+    pub streak: u32,
+}
+
+#[event]
+pub struct SubscriptionRenewedEvent {
+    pub mint: Pubkey,
+    pub plan: Pubkey,
+    pub token_account: Pubkey,
+    pub price: u64,
+    pub expiry: i64,
+    pub renewals: u32,
+}
+
+#[event]
+pub struct SubscriptionChargedEvent {
+    pub mint: Pubkey,
+    pub plan: Pubkey,
+    pub token_account: Pubkey,
+    pub merchant: Pubkey,
+    pub amount: u64,
+    pub expiry: i64,
+    pub renewals: u32,
+}
+
+#[event]
+pub struct SubscriptionCancelledEvent {
+    pub mint: Pubkey,
+    pub plan: Pubkey,
+    pub token_account: Pubkey,
+    pub refund: u64,
+}
+
+#[event]
+pub struct AccountEquationChangedEvent {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+    pub previous_tier: Option<u8>,
+    pub tier: Option<u8>,
+    pub equation_type: ChronoEquationType,
+    pub settled_balance: u64,
+}
+
+#[event]
+pub struct MintPausedEvent {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub stop_decay: bool,
+}
+
+#[event]
+pub struct MintResumedEvent {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TransferEvent {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    /// Withheld from what `to` received
+    pub fee: u64,
+    pub from_pre_balance: u64,
+    pub from_post_balance: u64,
+    pub to_pre_balance: u64,
+    pub to_post_balance: u64,
+    /// Decay realized into each side's pool when it was settled
+    pub from_decay: u64,
+    pub to_decay: u64,
+}
+
+#[event]
+pub struct BatchTransferEvent {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub authority: Pubkey,
+    pub recipients: u32,
+    pub total: u64,
+    /// Withheld across all recipients
+    pub fee: u64,
+    pub from_pre_balance: u64,
+    pub from_post_balance: u64,
+    pub from_decay: u64,
+}
+
+#[event]
+pub struct ClaimedEvent {
+    pub distributor: Pubkey,
+    pub claimant: Pubkey,
+    pub token_account: Pubkey,
+    pub index: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClawbackEvent {
+    pub distributor: Pubkey,
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SnapshotOpenedEvent {
+    pub mint: Pubkey,
+    pub snapshot: Pubkey,
+    pub snapshot_id: u64,
+    pub snapshot_time: i64,
+    pub record_deadline: i64,
+}
+
+#[event]
+pub struct BalanceRecordedEvent {
+    pub snapshot: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+#[event]
+pub struct EscrowCreatedEvent {
+    pub escrow: Pubkey,
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub unlock_time: i64,
+    pub arbiter: Option<Pubkey>,
+    pub decay_mode: EscrowDecayMode,
+}
+
+#[event]
+pub struct EscrowReleasedEvent {
+    pub escrow: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+    /// Vault balance paid out, after any decay
+    pub amount: u64,
+    /// Whether the vault went back to the depositor
+    pub refunded: bool,
+}
+
+#[event]
+pub struct StakeEvent {
+    pub stake_pool: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    /// Position's stake after the change
+    pub staked: u64,
+    pub total_staked: u64,
+    pub new_balance: u64,
+    pub unstaked: bool,
+}
+
+#[event]
+pub struct DecayHarvestedEvent {
+    pub stake_pool: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub reward_per_share: u128,
+}
+
+#[event]
+pub struct StakeRewardsClaimedEvent {
+    pub stake_pool: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub new_balance: u64,
+}
+
+#[event]
+pub struct StreamCreatedEvent {
+    pub stream: Pubkey,
+    pub mint: Pubkey,
+    pub sender_token_account: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub rate_per_unit: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub deposit: u64,
+}
+
+#[event]
+pub struct StreamWithdrawnEvent {
+    pub stream: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+    /// Total withdrawn from the stream so far
+    pub withdrawn: u64,
+    pub new_balance: u64,
+}
+
+#[event]
+pub struct StreamCancelledEvent {
+    pub stream: Pubkey,
+    pub authority: Pubkey,
+    /// Streamed amount the recipient had not withdrawn yet, it stays in its balance
+    pub paid_out: u64,
+    /// Unstreamed deposit released in the sender's balance
+    pub refunded: u64,
+}
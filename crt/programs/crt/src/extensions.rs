@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{ChronoEquationType, EquationParams, PauseType};
+use crate::state::{ChronoEquationType, DecayPause, EquationParams, PauseType, ReUpPolicy, TimeBase, TransferFeeConfig};
 
 #[account]
 #[derive(Debug, PartialEq)]
@@ -10,12 +10,28 @@ pub struct ChronoExtension {
     pub pause_type: PauseType,
     pub equation_params: EquationParams,
     pub reup_percentage: u8,
+    pub bump: u8,
+    pub reup_policy: Option<ReUpPolicy>,
+    /// Whether balances follow the mint's `EquationSchedule` instead of a single curve
+    pub has_schedule: bool,
+    pub time_base: TimeBase,
+    /// Whether transfers, mints and burns must check the mint's `ExemptionRegistry`
+    pub has_exemption_registry: bool,
+    pub transfer_fee: Option<TransferFeeConfig>,
+    /// Every mint pause that also stopped decay, oldest first. The account grows by one
+    /// `DecayPause` with each, see `ChronoExtension::space`.
+    pub decay_pauses: Vec<DecayPause>,
+    /// Time of the latest snapshot, settlements crossing it checkpoint balances
+    pub snapshot_time: Option<i64>,
+    /// End of that snapshot's recording window
+    pub snapshot_deadline: i64,
 }
 
 impl ChronoExtension {
     pub const EXTENSION_TYPE: u8 = 1;
     pub const LEN: usize = 8 + std::mem::size_of::<ChronoExtension>();
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         authority: Pubkey,
         program_id: Pubkey,
@@ -23,6 +39,8 @@ impl ChronoExtension {
         pause_type: PauseType,
         equation_params: EquationParams,
         reup_percentage: u8,
+        bump: u8,
+        reup_policy: Option<ReUpPolicy>,
     ) -> Self {
         Self {
             authority,
@@ -31,6 +49,64 @@ impl ChronoExtension {
             pause_type,
             equation_params,
             reup_percentage,
+            bump,
+            reup_policy,
+            has_schedule: false,
+            time_base: TimeBase::UnixTimestamp,
+            has_exemption_registry: false,
+            transfer_fee: None,
+            decay_pauses: Vec::new(),
+            snapshot_time: None,
+            snapshot_deadline: 0,
         }
     }
+
+    /// Account size once `decay_pauses` holds `pause_count` pauses
+    pub fn space(pause_count: usize) -> usize {
+        Self::LEN + pause_count * DecayPause::LEN
+    }
+}
+
+/// Curve params as stored by extensions created before the canonical bump was kept
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct LegacyEquationParams {
+    pub snapshot_time: Option<i64>,
+    pub expiration_time: Option<i64>,
+    pub inflation_rate: Option<u64>,
+    pub decay_rate: Option<u64>,
+    pub time_unit: Option<u64>,
+    pub slope: Option<i64>,
+    pub decay_constant: Option<f64>,
+    pub reup_boost: Option<u64>,
+}
+
+impl From<LegacyEquationParams> for EquationParams {
+    fn from(params: LegacyEquationParams) -> Self {
+        Self {
+            snapshot_time: params.snapshot_time,
+            expiration_time: params.expiration_time,
+            inflation_rate: params.inflation_rate,
+            decay_rate: params.decay_rate,
+            time_unit: params.time_unit,
+            slope: params.slope,
+            decay_constant: params.decay_constant,
+            reup_boost: params.reup_boost,
+            ..Default::default()
+        }
+    }
+}
+
+/// Layout of extensions created before the canonical bump was kept, read by `migrate_chrono_extension`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct LegacyChronoExtension {
+    pub authority: Pubkey,
+    pub program_id: Pubkey,
+    pub equation_type: ChronoEquationType,
+    pub pause_type: PauseType,
+    pub equation_params: LegacyEquationParams,
+    pub reup_percentage: u8,
+}
+
+impl LegacyChronoExtension {
+    pub const LEN: usize = 8 + std::mem::size_of::<LegacyChronoExtension>();
 }
\ No newline at end of file
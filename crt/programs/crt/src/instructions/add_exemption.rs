@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, ExemptionKind, MAX_EXEMPTIONS};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, settle, CurveConfig};
+
+/// `remaining_accounts` holds a writable (token account, decay pool, owner) triple per account
+/// the change affects, each is settled under its old exemption first. A token account exemption
+/// must pass that account, an owner program exemption the accounts its owners hold.
+#[derive(Accounts)]
+pub struct AddExemption<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        mut,
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Account<'info, ExemptionRegistry>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AddExemption<'info>>,
+    kind: ExemptionKind,
+    key: Pubkey,
+) -> Result<()> {
+    if ctx.accounts.mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    settle_affected(
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.exemption_registry,
+        &config,
+        ctx.program_id,
+        ctx.remaining_accounts,
+        kind,
+        &key,
+        current_time
+    )?;
+
+    let entries = ctx.accounts.exemption_registry.entries_mut(kind);
+    if entries.contains(&key) {
+        return Err(TokenError::AlreadyExempt.into());
+    }
+    if entries.len() >= MAX_EXEMPTIONS {
+        return Err(TokenError::ExemptionRegistryFull.into());
+    }
+    entries.push(key);
+
+    Ok(())
+}
+
+/// Settles every account an exemption change on `key` affects at `current_time`, so the decay
+/// accrued before the change is realized under the registry as it stood
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn settle_affected<'info>(
+    mint: &Pubkey,
+    registry: &ExemptionRegistry,
+    config: &CurveConfig,
+    program_id: &Pubkey,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    kind: ExemptionKind,
+    key: &Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    let triples = remaining_accounts.chunks_exact(3);
+    if !triples.remainder().is_empty() {
+        return Err(TokenError::UnsettledExemption.into());
+    }
+
+    let mut settled_key = false;
+    for triple in triples {
+        let mut token_account = Account::<TokenAccount>::try_from(&triple[0])?;
+        let mut decay_pool = Account::<DecayPool>::try_from(&triple[1])?;
+        let owner = &triple[2];
+
+        if token_account.mint != *mint {
+            return Err(TokenError::MintMismatch.into());
+        }
+        let expected_pool = Pubkey::create_program_address(
+            &[b"decay_pool", token_account.key().as_ref(), &[decay_pool.bump]],
+            program_id
+        ).map_err(|_| TokenError::InvalidDecayPool)?;
+        if decay_pool.key() != expected_pool {
+            return Err(TokenError::InvalidDecayPool.into());
+        }
+        if owner.key() != token_account.owner {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+        let affected = match kind {
+            ExemptionKind::TokenAccount => token_account.key() == *key,
+            ExemptionKind::OwnerProgram => owner.owner == key,
+        };
+        if !affected {
+            return Err(TokenError::UnsettledExemption.into());
+        }
+        settled_key |= token_account.key() == *key;
+
+        let exempt = registry.is_exempt(&token_account.key(), Some(owner))?;
+        let balance = evaluate_balance_unless_exempt(&token_account, exempt, config, current_time)?;
+        settle(&mut token_account, &mut decay_pool, balance, balance, config, current_time)?;
+
+        token_account.exit(program_id)?;
+        decay_pool.exit(program_id)?;
+    }
+
+    if kind == ExemptionKind::TokenAccount && !settled_key {
+        return Err(TokenError::UnsettledExemption.into());
+    }
+    Ok(())
+}
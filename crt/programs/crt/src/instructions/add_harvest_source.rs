@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, StakePool, MAX_HARVEST_SOURCES};
+use crate::error::TokenError;
+
+/// Accounts of `add_harvest_source` and `remove_harvest_source`
+#[derive(Accounts)]
+pub struct SetHarvestSource<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"stake_pool", mint.key().as_ref()],
+        bump = stake_pool.bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+    pub token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+}
+
+/// Opts a token account's decay pool into harvesting, only its owner can give up its decay
+pub fn handler(ctx: Context<SetHarvestSource>) -> Result<()> {
+    let token_account = &ctx.accounts.token_account;
+    if token_account.mint != ctx.accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if token_account.owner != ctx.accounts.authority.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    let harvest_sources = &mut ctx.accounts.stake_pool.harvest_sources;
+    if harvest_sources.contains(&token_account.key()) {
+        return Err(TokenError::AlreadyHarvestable.into());
+    }
+    if harvest_sources.len() >= MAX_HARVEST_SOURCES {
+        return Err(TokenError::HarvestSourcesFull.into());
+    }
+    harvest_sources.push(token_account.key());
+
+    Ok(())
+}
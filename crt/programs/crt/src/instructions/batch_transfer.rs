@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, FeeVault};
+use crate::error::TokenError;
+use crate::events::BatchTransferEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{ensure_spendable, evaluate_balance_unless_exempt, is_exempt, realized_decay, settle, CurveConfig};
+use super::transfer::{check_authority, withhold_fee};
+
+/// Sends one amount per recipient from a single sender, settled once.
+/// `remaining_accounts` holds a writable (token account, decay pool) pair and the token
+/// account's owner per amount, the owner is read for owner program exemptions.
+#[derive(Accounts)]
+pub struct BatchTransfer<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(mut)]
+    pub from: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", from.key().as_ref()],
+        bump = from_decay_pool.bump
+    )]
+    pub from_decay_pool: Account<'info, DecayPool>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+    /// CHECK: Owner of `from`, only read for owner program exemptions
+    #[account(address = from.owner)]
+    pub from_owner: Option<UncheckedAccount<'info>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchTransfer<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    let mint = &ctx.accounts.mint;
+    let extension = &ctx.accounts.chrono_extension;
+    let registry = ctx.accounts.exemption_registry.as_deref();
+    let sender = &mut ctx.accounts.from;
+
+    if amounts.is_empty() || ctx.remaining_accounts.len() != amounts.len() * 3 {
+        return Err(TokenError::InvalidBatch.into());
+    }
+    if sender.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if sender.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    let total = amounts.iter().try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(TokenError::Overflow)?;
+    check_authority(sender, &ctx.accounts.authority.key(), total)?;
+
+    // The sender is evaluated and settled once for the whole batch
+    let from_exempt = is_exempt(extension, registry, sender, ctx.accounts.from_owner.as_deref())?;
+    let from_snapshot = sender.last_balance_snapshot;
+    let from_balance = evaluate_balance_unless_exempt(sender, from_exempt, &config, current_time)?;
+    ensure_spendable(sender, from_balance, total, &config, current_time)?;
+
+    let mut total_fee = 0u64;
+    for (amount, triple) in amounts.iter().zip(ctx.remaining_accounts.chunks(3)) {
+        let mut recipient = Account::<TokenAccount>::try_from(&triple[0])?;
+        let mut recipient_decay_pool = Account::<DecayPool>::try_from(&triple[1])?;
+        let recipient_owner = &triple[2];
+
+        if recipient.mint != mint.key() {
+            return Err(TokenError::MintMismatch.into());
+        }
+        if recipient.key() == sender.key() {
+            return Err(TokenError::SelfTransfer.into());
+        }
+        if recipient.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+        if recipient_owner.key() != recipient.owner {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+        let expected_pool = Pubkey::create_program_address(
+            &[b"decay_pool", recipient.key().as_ref(), &[recipient_decay_pool.bump]],
+            ctx.program_id
+        ).map_err(|_| TokenError::InvalidDecayPool)?;
+        if recipient_decay_pool.key() != expected_pool {
+            return Err(TokenError::InvalidDecayPool.into());
+        }
+
+        let to_exempt = is_exempt(extension, registry, &recipient, Some(recipient_owner))?;
+        let to_balance = evaluate_balance_unless_exempt(&recipient, to_exempt, &config, current_time)?;
+        let new_to_balance = to_balance.checked_add(*amount)
+            .ok_or(TokenError::Overflow)?;
+        settle(&mut recipient, &mut recipient_decay_pool, to_balance, new_to_balance, &config, current_time)?;
+
+        // Same fee path as `transfer`, withheld from the credited recipient
+        let fee = withhold_fee(
+            extension,
+            *amount,
+            &mut recipient,
+            &mut ctx.accounts.from_decay_pool,
+            ctx.accounts.fee_vault.as_deref_mut()
+        )?;
+        total_fee = total_fee.checked_add(fee).ok_or(TokenError::Overflow)?;
+
+        // Written back right away so a recipient listed twice is read fresh
+        recipient.exit(ctx.program_id)?;
+        recipient_decay_pool.exit(ctx.program_id)?;
+    }
+
+    let new_from_balance = from_balance - total;
+    settle(sender, &mut ctx.accounts.from_decay_pool, from_balance, new_from_balance, &config, current_time)?;
+
+    emit!(BatchTransferEvent {
+        mint: mint.key(),
+        from: sender.key(),
+        authority: ctx.accounts.authority.key(),
+        recipients: amounts.len() as u32,
+        total,
+        fee: total_fee,
+        from_pre_balance: from_balance,
+        from_post_balance: new_from_balance,
+        from_decay: realized_decay(from_snapshot, from_balance),
+    });
+
+    Ok(())
+}
@@ -1,47 +1,97 @@
 use anchor_lang::prelude::*;
-use crate::state::{Mint, TokenAccount};
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry};
 use crate::error::TokenError;
-use crate::utils::evaluate_balance;
+use crate::utils::{ensure_spendable, evaluate_balance_unless_exempt, is_exempt, settle, CurveConfig};
 use crate::events::BurnEvent;
+use crate::extensions::ChronoExtension;
+use super::transfer::check_authority;
 
 
 #[derive(Accounts)]
 pub struct Burn<'info> {
     #[account(mut)]
     pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
     #[account(mut)]
     pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+    /// CHECK: Owner of `token_account`, only read for owner program exemptions
+    #[account(address = token_account.owner)]
+    pub owner: Option<UncheckedAccount<'info>>,
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<Burn>, amount: u64) -> Result<()> {
     let mint = &mut ctx.accounts.mint;
     let token_account = &mut ctx.accounts.token_account;
-    let current_time = Clock::get()?.unix_timestamp;
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
 
-    // Evaluate current balance
-    let current_balance = evaluate_balance(
-        token_account.last_balance_snapshot,
-        &token_account.current_chrono_equation,
-        token_account.creation_time,
-        current_time)?;
-
-    // Check if there are sufficient tokens to burn
-    if current_balance < amount {
-        return Err(TokenError::InsufficientFunds.into());
+    if token_account.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
     }
 
+    // Escrow and stake vaults back other accounts' claims, they are never burned from
+    if token_account.is_vault() {
+        return Err(TokenError::VaultAccount.into());
+    }
+
+    // Only the owner, or a delegate within its allowance, can burn
+    check_authority(token_account, &ctx.accounts.authority.key(), amount)?;
+
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        token_account,
+        ctx.accounts.owner.as_deref()
+    )?;
+
+    // Evaluate current balance
+    let current_balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
+
+    // Check if there are sufficient vested tokens to burn
+    ensure_spendable(token_account, current_balance, amount, &config, current_time)?;
+
     // Update mint supply
     mint.supply = mint.supply.checked_sub(amount).ok_or(TokenError::Overflow)?;
 
     // Update token account balance formula
     let new_balance = current_balance.checked_sub(amount).ok_or(TokenError::Overflow)?;
 
-    // Create a new balance formula that subtracts the burned amount
-    token_account.last_balance_snapshot = new_balance;
-
-    // // Update last update time
-    // token_account.last_update_time = current_time;
+    // Decay realized since the last update goes to the account's pool
+    settle(token_account, &mut ctx.accounts.decay_pool, current_balance, new_balance, &config, current_time)?;
 
     // Emit an event for the burn
     emit!(BurnEvent {
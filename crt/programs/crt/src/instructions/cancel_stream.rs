@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, Stream};
+use crate::error::TokenError;
+use crate::events::StreamCancelledEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{is_exempt, CurveConfig};
+use super::withdraw_stream::settle_streamed;
+
+/// Ends a stream early, signed by either side. The recipient keeps what has streamed and the
+/// rest of the deposit is released in the sender's balance.
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"stream", mint.key().as_ref(), stream.sender.as_ref(), &stream.stream_id.to_le_bytes()],
+        bump = stream.bump,
+        has_one = sender_token_account @ TokenError::InvalidStream,
+        has_one = recipient_token_account @ TokenError::InvalidStream
+    )]
+    pub stream: Account<'info, Stream>,
+    #[account(mut)]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", sender_token_account.key().as_ref()],
+        bump = sender_decay_pool.bump
+    )]
+    pub sender_decay_pool: Account<'info, DecayPool>,
+    #[account(mut)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", recipient_token_account.key().as_ref()],
+        bump = recipient_decay_pool.bump
+    )]
+    pub recipient_decay_pool: Account<'info, DecayPool>,
+    /// CHECK: Owner of `sender_token_account`, only read for owner program exemptions
+    #[account(address = sender_token_account.owner)]
+    pub sender_owner: Option<UncheckedAccount<'info>>,
+    /// CHECK: Owner of `recipient_token_account`, only read for owner program exemptions
+    #[account(address = recipient_token_account.owner)]
+    pub recipient_owner: Option<UncheckedAccount<'info>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelStream>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    let stream = &mut ctx.accounts.stream;
+    let authority = ctx.accounts.authority.key();
+
+    if authority != stream.sender && authority != stream.recipient {
+        return Err(TokenError::InvalidStreamAuthority.into());
+    }
+    if stream.cancelled || current_time >= stream.end_time {
+        return Err(TokenError::StreamEnded.into());
+    }
+    if ctx.accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if ctx.accounts.sender_token_account.is_frozen() || ctx.accounts.recipient_token_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // Settle both sides up to now, everything streamed so far stays with the recipient
+    let extension = &ctx.accounts.chrono_extension;
+    let registry = ctx.accounts.exemption_registry.as_deref();
+    let exempt = (
+        is_exempt(extension, registry, &ctx.accounts.sender_token_account, ctx.accounts.sender_owner.as_deref())?,
+        is_exempt(extension, registry, &ctx.accounts.recipient_token_account, ctx.accounts.recipient_owner.as_deref())?,
+    );
+    settle_streamed(
+        &mut ctx.accounts.sender_token_account,
+        &mut ctx.accounts.sender_decay_pool,
+        exempt.0,
+        &config,
+        current_time
+    )?;
+    settle_streamed(
+        &mut ctx.accounts.recipient_token_account,
+        &mut ctx.accounts.recipient_decay_pool,
+        exempt.1,
+        &config,
+        current_time
+    )?;
+
+    // Stop the stream now, the sender's remaining commitment is released
+    let streamed = stream.streamed(current_time);
+    let refund = stream.deposited - streamed;
+    let payout = streamed - stream.withdrawn;
+    let end_time = current_time.max(stream.start_time);
+    for token_account in [&mut ctx.accounts.sender_token_account, &mut ctx.accounts.recipient_token_account] {
+        let leg = token_account.streams.iter_mut()
+            .find(|leg| leg.stream == stream.key())
+            .ok_or(TokenError::InvalidStream)?;
+        leg.end_time = end_time;
+    }
+    stream.end_time = end_time;
+    stream.deposited = streamed;
+    stream.withdrawn = streamed;
+    stream.cancelled = true;
+
+    emit!(StreamCancelledEvent {
+        stream: stream.key(),
+        authority,
+        paid_out: payout,
+        refunded: refund,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, SubscriptionPlan, EquationSchedule, TierTable, ExemptionRegistry};
+use crate::error::TokenError;
+use crate::events::SubscriptionCancelledEvent;
+use crate::utils::{is_exempt, settle_transfer, CurveConfig};
+use crate::extensions::ChronoExtension;
+
+#[derive(Accounts)]
+pub struct CancelSubscription<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        seeds = [b"subscription_plan", mint.key().as_ref(), plan.merchant.as_ref(), &plan.plan_id.to_le_bytes()],
+        bump = plan.bump,
+        has_one = mint @ TokenError::MintMismatch
+    )]
+    pub plan: Account<'info, SubscriptionPlan>,
+    #[account(mut)]
+    pub subscriber: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", subscriber.key().as_ref()],
+        bump = subscriber_decay_pool.bump
+    )]
+    pub subscriber_decay_pool: Account<'info, DecayPool>,
+    #[account(
+        mut,
+        seeds = [b"subscription_escrow", plan.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", escrow.key().as_ref()],
+        bump = escrow_decay_pool.bump
+    )]
+    pub escrow_decay_pool: Account<'info, DecayPool>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelSubscription>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    let mint = &ctx.accounts.mint;
+    let plan = &ctx.accounts.plan;
+    let subscriber = &mut ctx.accounts.subscriber;
+
+    if subscriber.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if subscriber.owner != ctx.accounts.authority.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+    if subscriber.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if subscriber.subscription_plan != plan.key() {
+        return Err(TokenError::SubscriptionNotActive.into());
+    }
+
+    let refund = plan.refund_for(
+        subscriber.subscription_expiry,
+        subscriber.subscription_carried_until,
+        current_time
+    )?;
+    if refund > 0 {
+        // The escrow is owned by the plan, the subscriber by the signer
+        let extension = &ctx.accounts.chrono_extension;
+        let registry = ctx.accounts.exemption_registry.as_deref();
+        let exempt = (
+            is_exempt(extension, registry, &ctx.accounts.escrow, Some(&ctx.accounts.plan.to_account_info()))?,
+            is_exempt(extension, registry, subscriber, Some(&ctx.accounts.authority.to_account_info()))?,
+        );
+        settle_transfer(
+            &mut ctx.accounts.escrow,
+            &mut ctx.accounts.escrow_decay_pool,
+            subscriber,
+            &mut ctx.accounts.subscriber_decay_pool,
+            refund,
+            exempt,
+            &config,
+            current_time
+        )?;
+    }
+
+    // Clearing the plan keeps the refunded balance from lapsing with the old expiry
+    subscriber.subscription_plan = Pubkey::default();
+    subscriber.subscription_expiry = current_time;
+    subscriber.subscription_carried_until = 0;
+    subscriber.subscription_grace_period = 0;
+    subscriber.subscription_auto_renew = false;
+    subscriber.cancelled_plan = plan.key();
+
+    emit!(SubscriptionCancelledEvent {
+        mint: mint.key(),
+        plan: plan.key(),
+        token_account: subscriber.key(),
+        refund,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, Distributor, ClaimStatus, ChronoEquationType};
+use crate::error::TokenError;
+use crate::events::ClaimedEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, settle, CurveConfig};
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct Claim<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"distributor", mint.key().as_ref(), &distributor.distributor_id.to_le_bytes()],
+        bump = distributor.bump
+    )]
+    pub distributor: Account<'info, Distributor>,
+    #[account(
+        init,
+        payer = claimant,
+        space = ClaimStatus::LEN,
+        seeds = [b"claim_status", distributor.key().as_ref(), &index.to_le_bytes()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Claim>, index: u64, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    let claimant = ctx.accounts.claimant.key();
+    let distributor = &mut ctx.accounts.distributor;
+    let token_account = &mut ctx.accounts.token_account;
+
+    if token_account.mint != ctx.accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if token_account.owner != claimant {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+    if token_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if ctx.accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if current_time > distributor.claim_deadline {
+        return Err(TokenError::ClaimWindowClosed.into());
+    }
+    if !distributor.verify(index, &claimant, amount, &proof) {
+        return Err(TokenError::InvalidProof.into());
+    }
+
+    distributor.claimed = distributor.claimed.checked_add(amount)
+        .filter(|claimed| *claimed <= distributor.total)
+        .ok_or(TokenError::DistributorExhausted)?;
+
+    // The claimant signs as the owner, so it doubles as the exemption owner
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        token_account,
+        Some(&ctx.accounts.claimant.to_account_info())
+    )?;
+
+    // Decay on the airdrop starts now
+    let balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
+    let new_balance = balance.checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    settle(token_account, &mut ctx.accounts.decay_pool, balance, new_balance, &config, current_time)?;
+
+    // Claims into a vesting account are granted under the mint's vesting schedule, as in mint_to
+    if token_account.current_chrono_equation == ChronoEquationType::Vesting {
+        token_account.vesting_total = token_account.vesting_total.checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+    }
+
+    let claim_status = &mut ctx.accounts.claim_status;
+    claim_status.distributor = distributor.key();
+    claim_status.index = index;
+    claim_status.claimant = claimant;
+    claim_status.amount = amount;
+    claim_status.claimed_at = current_time;
+
+    emit!(ClaimedEvent {
+        distributor: distributor.key(),
+        claimant,
+        token_account: token_account.key(),
+        index,
+        amount,
+    });
+
+    Ok(())
+}
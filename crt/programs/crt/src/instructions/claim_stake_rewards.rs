@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use crate::events::StakeRewardsClaimedEvent;
+use super::stake::{settle_staker, Stake};
+
+/// Credits the position's harvested rewards to its token account
+pub fn handler(ctx: Context<Stake>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    ctx.accounts.stake_pool.release(current_time)?;
+    let stake_position = &mut ctx.accounts.stake_position;
+    stake_position.accrue(&ctx.accounts.stake_pool)?;
+
+    let amount = stake_position.pending_rewards;
+    if amount == 0 {
+        return Err(TokenError::NoStakeRewards.into());
+    }
+
+    let new_balance = settle_staker(ctx.accounts, amount, 0, current_time)?;
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    ctx.accounts.stake_position.pending_rewards = 0;
+    stake_pool.total_claimed = stake_pool.total_claimed.checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+
+    emit!(StakeRewardsClaimedEvent {
+        stake_pool: stake_pool.key(),
+        token_account: ctx.accounts.token_account.key(),
+        amount,
+        new_balance,
+    });
+
+    Ok(())
+}
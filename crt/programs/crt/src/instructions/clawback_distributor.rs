@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, Distributor};
+use crate::error::TokenError;
+use crate::events::ClawbackEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, settle, CurveConfig};
+
+/// Returns a distributor's unclaimed tokens to its authority once the claim window closes
+#[derive(Accounts)]
+pub struct ClawbackDistributor<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"distributor", mint.key().as_ref(), &distributor.distributor_id.to_le_bytes()],
+        bump = distributor.bump,
+        has_one = authority @ TokenError::InvalidAuthority
+    )]
+    pub distributor: Account<'info, Distributor>,
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClawbackDistributor>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    let distributor = &mut ctx.accounts.distributor;
+    let token_account = &mut ctx.accounts.token_account;
+
+    if token_account.mint != ctx.accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if ctx.accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if token_account.owner != distributor.authority {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+    if current_time <= distributor.claim_deadline {
+        return Err(TokenError::ClaimWindowOpen.into());
+    }
+
+    let amount = distributor.unclaimed();
+    if amount == 0 {
+        return Err(TokenError::DistributorExhausted.into());
+    }
+    distributor.claimed = distributor.total;
+
+    // The distributor authority signs as the owner, so it doubles as the exemption owner
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        token_account,
+        Some(&ctx.accounts.authority.to_account_info())
+    )?;
+    let balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
+    let new_balance = balance.checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    settle(token_account, &mut ctx.accounts.decay_pool, balance, new_balance, &config, current_time)?;
+
+    emit!(ClawbackEvent {
+        distributor: distributor.key(),
+        authority: distributor.authority,
+        token_account: token_account.key(),
+        amount,
+    });
+
+    Ok(())
+}
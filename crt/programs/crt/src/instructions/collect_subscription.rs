@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, SubscriptionPlan, EquationSchedule, TierTable, ExemptionRegistry, FeeVault};
+use crate::error::TokenError;
+use crate::events::SubscriptionChargedEvent;
+use crate::utils::{is_exempt, settle_transfer, CurveConfig};
+use crate::extensions::ChronoExtension;
+use super::transfer::withhold_fee;
+
+#[derive(Accounts)]
+pub struct CollectSubscription<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        seeds = [b"subscription_plan", mint.key().as_ref(), merchant.key().as_ref(), &plan.plan_id.to_le_bytes()],
+        bump = plan.bump,
+        has_one = mint @ TokenError::MintMismatch,
+        has_one = merchant @ TokenError::SubscriptionPlanMismatch,
+        has_one = merchant_token_account @ TokenError::SubscriptionPlanMismatch
+    )]
+    pub plan: Account<'info, SubscriptionPlan>,
+    #[account(mut)]
+    pub subscriber: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", subscriber.key().as_ref()],
+        bump = subscriber_decay_pool.bump
+    )]
+    pub subscriber_decay_pool: Account<'info, DecayPool>,
+    #[account(mut)]
+    pub merchant_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", merchant_token_account.key().as_ref()],
+        bump = merchant_decay_pool.bump
+    )]
+    pub merchant_decay_pool: Account<'info, DecayPool>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+    /// CHECK: Owner of `subscriber`, only read for owner program exemptions
+    #[account(address = subscriber.owner)]
+    pub subscriber_owner: Option<UncheckedAccount<'info>>,
+    pub merchant: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CollectSubscription>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    let mint = &ctx.accounts.mint;
+    let plan = &ctx.accounts.plan;
+    let subscriber = &mut ctx.accounts.subscriber;
+    let merchant = &mut ctx.accounts.merchant_token_account;
+
+    if subscriber.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if subscriber.subscription_plan != plan.key() {
+        return Err(TokenError::SubscriptionPlanMismatch.into());
+    }
+    if !subscriber.subscription_auto_renew {
+        return Err(TokenError::SubscriptionCancelled.into());
+    }
+    if subscriber.is_frozen() || merchant.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if subscriber.key() == merchant.key() {
+        return Err(TokenError::SelfTransfer.into());
+    }
+
+    // At most one charge per period, the next one opens when the paid period ends
+    if current_time < subscriber.subscription_expiry {
+        return Err(TokenError::SubscriptionPeriodNotElapsed.into());
+    }
+    if plan.max_renewals != 0 && subscriber.subscription_renewals >= plan.max_renewals {
+        return Err(TokenError::MaxRenewalsReached.into());
+    }
+
+    // Periods chain back to back while the subscription is within grace, otherwise restart now
+    let period_start = match subscriber.subscription_cutoff() {
+        Some(cutoff) if current_time <= cutoff => subscriber.subscription_expiry,
+        _ => current_time,
+    };
+
+    // Collections are transfers, exemptions and the mint's fee apply the same way
+    let extension = &ctx.accounts.chrono_extension;
+    let registry = ctx.accounts.exemption_registry.as_deref();
+    let exempt = (
+        is_exempt(extension, registry, subscriber, ctx.accounts.subscriber_owner.as_deref())?,
+        is_exempt(extension, registry, merchant, Some(&ctx.accounts.merchant.to_account_info()))?,
+    );
+
+    settle_transfer(
+        subscriber,
+        &mut ctx.accounts.subscriber_decay_pool,
+        merchant,
+        &mut ctx.accounts.merchant_decay_pool,
+        plan.price,
+        exempt,
+        &config,
+        current_time
+    )?;
+    withhold_fee(
+        extension,
+        plan.price,
+        merchant,
+        &mut ctx.accounts.subscriber_decay_pool,
+        ctx.accounts.fee_vault.as_deref_mut()
+    )?;
+
+    subscriber.subscription_expiry = period_start.checked_add(plan.period)
+        .ok_or(TokenError::Overflow)?;
+    subscriber.subscription_grace_period = plan.grace_period;
+    subscriber.subscription_renewals = subscriber.subscription_renewals.checked_add(1)
+        .ok_or(TokenError::Overflow)?;
+
+    emit!(SubscriptionChargedEvent {
+        mint: mint.key(),
+        plan: plan.key(),
+        token_account: subscriber.key(),
+        merchant: plan.merchant,
+        amount: plan.price,
+        expiry: subscriber.subscription_expiry,
+        renewals: subscriber.subscription_renewals,
+    });
+
+    Ok(())
+}
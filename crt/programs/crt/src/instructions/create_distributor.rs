@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, Distributor};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+
+#[derive(Accounts)]
+#[instruction(distributor_id: u64)]
+pub struct CreateDistributor<'info> {
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        init,
+        payer = payer,
+        space = Distributor::LEN,
+        seeds = [b"distributor", mint.key().as_ref(), &distributor_id.to_le_bytes()],
+        bump
+    )]
+    pub distributor: Account<'info, Distributor>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateDistributor>,
+    distributor_id: u64,
+    merkle_root: [u8; 32],
+    total: u64,
+    claim_deadline: i64,
+) -> Result<()> {
+    let mint = &mut ctx.accounts.mint;
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+
+    if mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if total == 0 || claim_deadline <= current_time {
+        return Err(TokenError::InvalidDistributor.into());
+    }
+
+    // The whole airdrop is issued up front, within the supply cap and emission allowance
+    mint.issue(total, current_time)?;
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.mint = mint.key();
+    distributor.authority = ctx.accounts.authority.key();
+    distributor.distributor_id = distributor_id;
+    distributor.merkle_root = merkle_root;
+    distributor.total = total;
+    distributor.claimed = 0;
+    distributor.claim_deadline = claim_deadline;
+    distributor.bump = ctx.bumps.distributor;
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, AccountState, Escrow, EscrowDecayMode,
+};
+use crate::error::TokenError;
+use crate::events::EscrowCreatedEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{is_exempt, settle_transfer, CurveConfig};
+
+/// Locks `amount` from the depositor's account in a program-owned vault until it is released
+/// to the beneficiary or refunded. An exempt vault is held flat without the chrono hook.
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct CreateEscrow<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        init,
+        payer = depositor,
+        space = Escrow::LEN,
+        seeds = [b"escrow", mint.key().as_ref(), depositor.key().as_ref(), &escrow_id.to_le_bytes()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    #[account(
+        init,
+        payer = depositor,
+        space = TokenAccount::LEN,
+        seeds = [b"escrow_vault", escrow.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = depositor,
+        space = DecayPool::LEN,
+        seeds = [b"decay_pool", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_decay_pool: Account<'info, DecayPool>,
+    #[account(mut)]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", depositor_token_account.key().as_ref()],
+        bump = depositor_decay_pool.bump
+    )]
+    pub depositor_decay_pool: Account<'info, DecayPool>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateEscrow>,
+    escrow_id: u64,
+    amount: u64,
+    beneficiary: Pubkey,
+    unlock_time: i64,
+    arbiter: Option<Pubkey>,
+    decay_mode: EscrowDecayMode,
+) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    let mint = &ctx.accounts.mint;
+    let depositor = ctx.accounts.depositor.key();
+
+    if ctx.accounts.depositor_token_account.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if ctx.accounts.depositor_token_account.owner != depositor {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+    if ctx.accounts.depositor_token_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    // Without an arbiter the lock period is the only way out
+    if amount == 0 || (arbiter.is_none() && unlock_time <= current_time) {
+        return Err(TokenError::InvalidEscrow.into());
+    }
+
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.mint = mint.key();
+    escrow.depositor = depositor;
+    escrow.depositor_token_account = ctx.accounts.depositor_token_account.key();
+    escrow.beneficiary = beneficiary;
+    escrow.vault = ctx.accounts.vault.key();
+    escrow.escrow_id = escrow_id;
+    escrow.amount = amount;
+    escrow.unlock_time = unlock_time;
+    escrow.arbiter = arbiter;
+    escrow.decay_mode = decay_mode;
+    escrow.settled = false;
+    escrow.bump = ctx.bumps.escrow;
+
+    // The vault is owned by the escrow, so only the program can move its funds
+    let vault = &mut ctx.accounts.vault;
+    vault.mint = mint.key();
+    vault.owner = escrow.key();
+    vault.state = if escrow.is_exempt() { AccountState::Vault } else { AccountState::Initialized };
+    vault.creation_time = current_time;
+    vault.last_update_time = current_time;
+    vault.last_balance_snapshot = 0;
+    vault.current_chrono_equation = mint.chrono_equation;
+
+    let vault_decay_pool = &mut ctx.accounts.vault_decay_pool;
+    vault_decay_pool.token_account = vault.key();
+    vault_decay_pool.amount = 0;
+    vault_decay_pool.bump = ctx.bumps.vault_decay_pool;
+
+    let depositor_exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        &ctx.accounts.depositor_token_account,
+        Some(&ctx.accounts.depositor.to_account_info())
+    )?;
+    let vault_exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        vault,
+        Some(&escrow.to_account_info())
+    )?;
+
+    settle_transfer(
+        &mut ctx.accounts.depositor_token_account,
+        &mut ctx.accounts.depositor_decay_pool,
+        vault,
+        vault_decay_pool,
+        amount,
+        (depositor_exempt, vault_exempt),
+        &config,
+        current_time
+    )?;
+
+    emit!(EscrowCreatedEvent {
+        escrow: escrow.key(),
+        mint: mint.key(),
+        depositor,
+        beneficiary,
+        amount,
+        unlock_time,
+        arbiter,
+        decay_mode,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, Stream, StreamLeg};
+use crate::error::TokenError;
+use crate::events::StreamCreatedEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, ensure_spendable, is_exempt, settle, CurveConfig};
+
+/// Opens a stream. Its deposit stays in the sender's balance, locked and held flat, and
+/// streams out of it into the recipient's balance between `start_time` and `end_time`.
+#[derive(Accounts)]
+#[instruction(stream_id: u64)]
+pub struct CreateStream<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        init,
+        payer = sender,
+        space = Stream::LEN,
+        seeds = [b"stream", mint.key().as_ref(), sender.key().as_ref(), &stream_id.to_le_bytes()],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+    #[account(
+        mut,
+        realloc = TokenAccount::space(sender_token_account.streams.len() + 1)
+            .max(sender_token_account.to_account_info().data_len()),
+        realloc::payer = sender,
+        realloc::zero = false
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", sender_token_account.key().as_ref()],
+        bump = sender_decay_pool.bump
+    )]
+    pub sender_decay_pool: Account<'info, DecayPool>,
+    #[account(
+        mut,
+        realloc = TokenAccount::space(recipient_token_account.streams.len() + 1)
+            .max(recipient_token_account.to_account_info().data_len()),
+        realloc::payer = sender,
+        realloc::zero = false
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateStream>,
+    stream_id: u64,
+    rate_per_unit: u64,
+    start_time: i64,
+    end_time: i64,
+) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    let mint = &ctx.accounts.mint;
+    let sender_token_account = &mut ctx.accounts.sender_token_account;
+    let recipient_token_account = &mut ctx.accounts.recipient_token_account;
+
+    if sender_token_account.mint != mint.key() || recipient_token_account.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if sender_token_account.owner != ctx.accounts.sender.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+    if sender_token_account.is_frozen() || recipient_token_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    // Vaults only move through their own instructions, a stream would unbalance them
+    if sender_token_account.key() == recipient_token_account.key()
+        || recipient_token_account.is_vault()
+        || start_time < current_time
+    {
+        return Err(TokenError::InvalidStream.into());
+    }
+    let deposit = Stream::deposit_for(rate_per_unit, start_time, end_time)?;
+
+    // Settle the sender first, the deposit is held flat from now on
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        sender_token_account,
+        Some(&ctx.accounts.sender.to_account_info())
+    )?;
+    let balance = evaluate_balance_unless_exempt(sender_token_account, exempt, &config, current_time)?;
+    ensure_spendable(sender_token_account, balance, deposit, &config, current_time)?;
+    settle(sender_token_account, &mut ctx.accounts.sender_decay_pool, balance, balance, &config, current_time)?;
+
+    // The stream starts no earlier than now, so the recipient's past balance is unaffected
+    let stream = &mut ctx.accounts.stream;
+    let leg = StreamLeg {
+        stream: stream.key(),
+        rate_per_unit,
+        start_time,
+        end_time,
+        outgoing: true,
+    };
+    sender_token_account.streams.push(leg);
+    recipient_token_account.streams.push(StreamLeg { outgoing: false, ..leg });
+
+    stream.mint = mint.key();
+    stream.sender = ctx.accounts.sender.key();
+    stream.sender_token_account = sender_token_account.key();
+    stream.recipient = recipient_token_account.owner;
+    stream.recipient_token_account = recipient_token_account.key();
+    stream.stream_id = stream_id;
+    stream.rate_per_unit = rate_per_unit;
+    stream.start_time = start_time;
+    stream.end_time = end_time;
+    stream.deposited = deposit;
+    stream.withdrawn = 0;
+    stream.cancelled = false;
+    stream.bump = ctx.bumps.stream;
+
+    emit!(StreamCreatedEvent {
+        stream: stream.key(),
+        mint: mint.key(),
+        sender_token_account: stream.sender_token_account,
+        recipient_token_account: stream.recipient_token_account,
+        rate_per_unit,
+        start_time,
+        end_time,
+        deposit,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, SubscriptionPlan, RefundPolicy, AccountState, BASIS_POINTS};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+
+#[derive(Accounts)]
+#[instruction(plan_id: u64)]
+pub struct CreateSubscriptionPlan<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        init,
+        payer = payer,
+        space = SubscriptionPlan::LEN,
+        seeds = [b"subscription_plan", mint.key().as_ref(), merchant.key().as_ref(), &plan_id.to_le_bytes()],
+        bump
+    )]
+    pub plan: Account<'info, SubscriptionPlan>,
+    pub merchant_token_account: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = TokenAccount::LEN,
+        seeds = [b"subscription_escrow", plan.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = DecayPool::LEN,
+        seeds = [b"decay_pool", escrow.key().as_ref()],
+        bump
+    )]
+    pub escrow_decay_pool: Account<'info, DecayPool>,
+    pub merchant: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateSubscriptionPlan>,
+    plan_id: u64,
+    price: u64,
+    period: i64,
+    grace_period: i64,
+    max_renewals: u32,
+    refund_policy: RefundPolicy,
+) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let mint = &ctx.accounts.mint;
+    let merchant_token_account = &ctx.accounts.merchant_token_account;
+
+    if merchant_token_account.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if merchant_token_account.owner != ctx.accounts.merchant.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    if price == 0 || period <= 0 || grace_period < 0 {
+        return Err(TokenError::InvalidSubscriptionPlan.into());
+    }
+    if let RefundPolicy::ProRataMinusFee { fee_bps } = refund_policy {
+        if fee_bps > BASIS_POINTS {
+            return Err(TokenError::InvalidSubscriptionPlan.into());
+        }
+    }
+
+    let plan = &mut ctx.accounts.plan;
+    plan.merchant = ctx.accounts.merchant.key();
+    plan.mint = mint.key();
+    plan.merchant_token_account = merchant_token_account.key();
+    plan.escrow_token_account = ctx.accounts.escrow.key();
+    plan.plan_id = plan_id;
+    plan.price = price;
+    plan.period = period;
+    plan.grace_period = grace_period;
+    plan.max_renewals = max_renewals;
+    plan.refund_policy = refund_policy;
+    plan.bump = ctx.bumps.plan;
+
+    // The escrow is owned by the plan, so only the program can move its funds
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.mint = mint.key();
+    escrow.owner = plan.key();
+    escrow.state = AccountState::Initialized;
+    escrow.creation_time = current_time;
+    escrow.last_update_time = current_time;
+    escrow.last_balance_snapshot = 0;
+    escrow.current_chrono_equation = mint.chrono_equation;
+
+    let escrow_decay_pool = &mut ctx.accounts.escrow_decay_pool;
+    escrow_decay_pool.token_account = escrow.key();
+    escrow_decay_pool.amount = 0;
+    escrow_decay_pool.bump = ctx.bumps.escrow_decay_pool;
+
+    Ok(())
+}
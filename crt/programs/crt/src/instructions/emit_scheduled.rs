@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry};
+use crate::error::TokenError;
+use crate::events::MintToEvent;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, settle, CurveConfig};
+use crate::extensions::ChronoExtension;
+
+/// Mints the unlocked emission allowance into the treasury, callable by anyone
+#[derive(Accounts)]
+pub struct EmitScheduled<'info> {
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+    /// CHECK: Owner of `token_account`, only read for owner program exemptions
+    #[account(address = token_account.owner)]
+    pub owner: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<EmitScheduled>) -> Result<()> {
+    let mint = &mut ctx.accounts.mint;
+    let token_account = &mut ctx.accounts.token_account;
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    if token_account.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+
+    let emission = mint.emission.ok_or(TokenError::MissingEmissionSchedule)?;
+    if token_account.key() != emission.treasury {
+        return Err(TokenError::TreasuryMismatch.into());
+    }
+
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        token_account,
+        ctx.accounts.owner.as_deref()
+    )?;
+
+    let amount = mint.emittable(current_time);
+    if amount == 0 {
+        return Err(TokenError::NothingToEmit.into());
+    }
+    mint.issue(amount, current_time)?;
+
+    let current_balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
+    let new_balance = current_balance.checked_add(amount).ok_or(TokenError::Overflow)?;
+
+    // Decay realized since the last update goes to the treasury's pool
+    settle(token_account, &mut ctx.accounts.decay_pool, current_balance, new_balance, &config, current_time)?;
+
+    emit!(MintToEvent {
+        mint: mint.key(),
+        token_account: token_account.key(),
+        amount,
+        new_balance,
+    });
+
+    Ok(())
+}
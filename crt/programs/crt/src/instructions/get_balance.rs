@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, EquationSchedule, TierTable, ExemptionRegistry};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, locked_balance, CurveConfig};
+
+#[derive(Accounts)]
+pub struct GetBalance<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    pub token_account: Account<'info, TokenAccount>,
+    /// CHECK: Owner of `token_account`, only read for owner program exemptions
+    #[account(address = token_account.owner)]
+    pub owner: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct BalanceView {
+    /// Evaluated balance at the current time
+    pub balance: u64,
+    /// Part of `balance` that can be transferred
+    pub vested: u64,
+    /// Part of `balance` still locked by a vesting curve or owed to outgoing streams
+    pub locked: u64,
+    /// Part of `balance` streamed to the account since it was last settled
+    pub streamed: u64,
+}
+
+pub fn handler(ctx: Context<GetBalance>) -> Result<BalanceView> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let token_account = &ctx.accounts.token_account;
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    if token_account.mint != ctx.accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        token_account,
+        ctx.accounts.owner.as_deref()
+    )?;
+    let balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
+    let locked = locked_balance(token_account, config.params_for(token_account)?, balance, current_time);
+    let (streamed, _) = token_account.streamed_between(token_account.last_update_time, current_time);
+
+    Ok(BalanceView {
+        balance,
+        vested: balance - locked,
+        locked,
+        streamed,
+    })
+}
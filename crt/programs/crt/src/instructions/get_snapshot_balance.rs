@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{Snapshot, BalanceRecord};
+
+/// Read-only view of a recorded balance, for voting programs to CPI into
+#[derive(Accounts)]
+pub struct GetSnapshotBalance<'info> {
+    #[account(
+        seeds = [b"snapshot", snapshot.mint.as_ref(), &snapshot.snapshot_id.to_le_bytes()],
+        bump = snapshot.bump
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+    #[account(
+        seeds = [b"balance_record", snapshot.key().as_ref(), balance_record.token_account.as_ref()],
+        bump = balance_record.bump
+    )]
+    pub balance_record: Account<'info, BalanceRecord>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SnapshotBalanceView {
+    pub owner: Pubkey,
+    pub balance: u64,
+    pub snapshot_time: i64,
+    /// Sum of all balances recorded so far
+    pub recorded_total: u64,
+}
+
+pub fn handler(ctx: Context<GetSnapshotBalance>) -> Result<SnapshotBalanceView> {
+    let snapshot = &ctx.accounts.snapshot;
+    let balance_record = &ctx.accounts.balance_record;
+
+    Ok(SnapshotBalanceView {
+        owner: balance_record.owner,
+        balance: balance_record.balance,
+        snapshot_time: snapshot.snapshot_time,
+        recorded_total: snapshot.recorded_total,
+    })
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, StakePool};
+use crate::error::TokenError;
+use crate::events::DecayHarvestedEvent;
+use crate::extensions::ChronoExtension;
+
+/// Sweeps the decay already realized into an opted in account's pool to the mint's stakers,
+/// who are paid it over the pool's reward duration. Permissionless, keepers crank it across accounts.
+#[derive(Accounts)]
+pub struct HarvestDecay<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        mut,
+        seeds = [b"stake_pool", mint.key().as_ref()],
+        bump = stake_pool.bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+}
+
+pub fn handler(ctx: Context<HarvestDecay>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    let decay_pool = &mut ctx.accounts.decay_pool;
+
+    if ctx.accounts.token_account.mint != ctx.accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if ctx.accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if !stake_pool.harvest_sources.contains(&ctx.accounts.token_account.key()) {
+        return Err(TokenError::NotHarvestable.into());
+    }
+
+    let amount = decay_pool.amount;
+    if amount == 0 {
+        return Err(TokenError::NothingToHarvest.into());
+    }
+
+    stake_pool.add_rewards(amount, current_time)?;
+    decay_pool.amount = 0;
+
+    emit!(DecayHarvestedEvent {
+        stake_pool: stake_pool.key(),
+        token_account: ctx.accounts.token_account.key(),
+        amount,
+        reward_per_share: stake_pool.reward_per_share,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, ExemptionRegistry};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+
+#[derive(Accounts)]
+pub struct InitializeExemptionRegistry<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        init,
+        payer = payer,
+        space = ExemptionRegistry::LEN,
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump
+    )]
+    pub exemption_registry: Account<'info, ExemptionRegistry>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeExemptionRegistry>) -> Result<()> {
+    let mint = &ctx.accounts.mint;
+
+    if mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+
+    let exemption_registry = &mut ctx.accounts.exemption_registry;
+    exemption_registry.mint = mint.key();
+    exemption_registry.token_accounts = Vec::new();
+    exemption_registry.owner_programs = Vec::new();
+    exemption_registry.bump = ctx.bumps.exemption_registry;
+
+    ctx.accounts.chrono_extension.has_exemption_registry = true;
+
+    Ok(())
+}
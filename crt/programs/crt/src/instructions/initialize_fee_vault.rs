@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, FeeVault};
+
+#[derive(Accounts)]
+pub struct InitializeFeeVault<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = FeeVault::LEN,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeFeeVault>) -> Result<()> {
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    fee_vault.mint = ctx.accounts.mint.key();
+    fee_vault.withheld = 0;
+    fee_vault.bump = ctx.bumps.fee_vault;
+
+    Ok(())
+}
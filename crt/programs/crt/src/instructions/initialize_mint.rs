@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::{Mint, DecayPool, EquationParams};
+use crate::state::{Mint, EquationParams};
 use crate::error::TokenError;
-use crate::state::{ChronoEquationType, PauseType};
+use crate::state::{ChronoEquationType, PauseType, ReUpPolicy, TimeBase, TransferFeeConfig, EmissionSchedule};
 use crate::extensions::ChronoExtension;
 
 #[derive(Accounts)]
@@ -23,18 +23,24 @@ pub struct InitializeMint<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<InitializeMint>,
     decimals: u8,
     supply: u64,
     freeze_authority: Option<Pubkey>,
-    _bump: u8,
     enable_chrono_hook: bool,
     chrono_hook_program_id: Option<Pubkey>,
     equation_type: Option<ChronoEquationType>,
     pause_type: Option<PauseType>,
     equation_params: Option<EquationParams>,
     reup_percentage: Option<u8>,
+    reup_policy: Option<ReUpPolicy>,
+    time_base: Option<TimeBase>,
+    transfer_fee: Option<TransferFeeConfig>,
+    max_supply: Option<u64>,
+    emission: Option<EmissionSchedule>,
+    pause_authority: Option<Pubkey>,
 ) -> Result<()> {
     let mint = &mut ctx.accounts.mint;
 
@@ -42,40 +48,68 @@ pub fn handler(
         return Err(TokenError::AlreadyInUse.into());
     }
 
+    let equation_type = equation_type.ok_or(TokenError::MissingEquationType)?;
+    let pause_type = pause_type.ok_or(TokenError::MissingPauseType)?;
+
+    // Params are checked against what the chosen curve reads, before anything is written
+    let mut equation_params = equation_params.unwrap_or_default();
+    let time_base = time_base.unwrap_or_default();
+    equation_params.apply_half_life(time_base)?;
+    equation_type.validate_params(&equation_params, time_base)?;
+    if let Some(transfer_fee) = transfer_fee {
+        transfer_fee.validate()?;
+    }
+    if max_supply.is_some_and(|max_supply| supply > max_supply) {
+        return Err(TokenError::MaxSupplyExceeded.into());
+    }
+    if let Some(emission) = emission {
+        emission.validate()?;
+    }
+
     mint.mint_authority = ctx.accounts.authority.key();
     mint.decimals = decimals;
     mint.supply = supply;
-    mint.freeze_authority = Some(freeze_authority.expect("Error with freeze authority value"));
-    mint.chrono_equation = equation_type.expect("error with equation type");
-    mint.pause_type = pause_type.expect("error with pause type");
+    mint.max_supply = max_supply;
+    mint.emission = emission;
+    mint.emitted = 0;
+    mint.pause_authority = pause_authority;
+    mint.paused = false;
+    mint.freeze_authority = freeze_authority;
+    mint.chrono_equation = equation_type;
+    mint.pause_type = pause_type;
+    mint.is_initialized = true;
+
+    // Curve params live on the extension whether or not the hook is enabled, balances read them from there
+    let chrono_extension = &mut ctx.accounts.chrono_extension;
+    chrono_extension.authority = ctx.accounts.authority.key();
+    chrono_extension.equation_type = equation_type;
+    chrono_extension.pause_type = pause_type;
+    chrono_extension.equation_params = equation_params;
+    chrono_extension.bump = ctx.bumps.chrono_extension;
+    chrono_extension.time_base = time_base;
+    chrono_extension.transfer_fee = transfer_fee;
 
     if enable_chrono_hook {
-        if let (Some(program_id), Some(eq_type), Some(p_type), Some(params)) = (
-            chrono_hook_program_id,
-            equation_type,
-            pause_type,
-            equation_params,
-        ) {
-            let reup_percentage = match (p_type, reup_percentage) {
-                (PauseType::ReUp, Some(percentage)) if percentage <= 100 => percentage,
-                (PauseType::ReUp, Some(_)) => return Err(TokenError::InvalidReUpPercentage.into()),
-                (PauseType::ReUp, None) => return Err(TokenError::MissingReUpPercentage.into()),
-                (_, Some(_)) => return Err(TokenError::UnexpectedReUpPercentage.into()),
-                (_, None) => 0, // Default value when not ReUp
-            };
+        let program_id = chrono_hook_program_id.ok_or(ProgramError::InvalidArgument)?;
+
+        let reup_percentage = match (pause_type, reup_percentage) {
+            (PauseType::ReUp, Some(percentage)) if percentage <= 100 => percentage,
+            (PauseType::ReUp, Some(_)) => return Err(TokenError::InvalidReUpPercentage.into()),
+            (PauseType::ReUp, None) => return Err(TokenError::MissingReUpPercentage.into()),
+            (_, Some(_)) => return Err(TokenError::UnexpectedReUpPercentage.into()),
+            (_, None) => 0, // Default value when not ReUp
+        };
 
-            let chrono_extension = &mut ctx.accounts.chrono_extension;
-            chrono_extension.authority = ctx.accounts.authority.key();
-            chrono_extension.program_id = program_id;
-            chrono_extension.equation_type = eq_type;
-            chrono_extension.pause_type = p_type;
-            chrono_extension.equation_params = params;
-            chrono_extension.reup_percentage = reup_percentage;
-        } else {
-            return Err(ProgramError::InvalidArgument.into());
+        match (pause_type, reup_policy) {
+            (PauseType::ReUp, Some(policy)) => policy.validate()?,
+            (_, Some(_)) => return Err(TokenError::UnexpectedReUpPolicy.into()),
+            (_, None) => {}
         }
-    }
 
+        chrono_extension.program_id = program_id;
+        chrono_extension.reup_percentage = reup_percentage;
+        chrono_extension.reup_policy = reup_policy;
+    }
 
     Ok(())
 }
\ No newline at end of file
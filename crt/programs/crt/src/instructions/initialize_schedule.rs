@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, EquationSchedule, SchedulePhase};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+
+#[derive(Accounts)]
+pub struct InitializeSchedule<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        init,
+        payer = payer,
+        space = EquationSchedule::LEN,
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump
+    )]
+    pub schedule: Account<'info, EquationSchedule>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeSchedule>, mut phases: Vec<SchedulePhase>) -> Result<()> {
+    let mint = &ctx.accounts.mint;
+
+    if mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+
+    EquationSchedule::validate(&mut phases, ctx.accounts.chrono_extension.time_base)?;
+
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.mint = mint.key();
+    schedule.phases = phases;
+    schedule.bump = ctx.bumps.schedule;
+
+    ctx.accounts.chrono_extension.has_schedule = true;
+
+    Ok(())
+}
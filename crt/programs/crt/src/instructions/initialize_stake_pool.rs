@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, AccountState, StakePool};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+
+/// Opens staking for a mint. From then on the decay pools of accounts the mint authority
+/// adds as harvest sources can be harvested to stakers instead of waiting for a ReUp.
+#[derive(Accounts)]
+pub struct InitializeStakePool<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        init,
+        payer = payer,
+        space = StakePool::LEN,
+        seeds = [b"stake_pool", mint.key().as_ref()],
+        bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+    #[account(
+        init,
+        payer = payer,
+        space = TokenAccount::LEN,
+        seeds = [b"stake_vault", stake_pool.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = DecayPool::LEN,
+        seeds = [b"decay_pool", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_decay_pool: Account<'info, DecayPool>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeStakePool>, reward_duration: i64) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let mint = &ctx.accounts.mint;
+
+    if mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+    if reward_duration <= 0 {
+        return Err(TokenError::InvalidRewardDuration.into());
+    }
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    stake_pool.mint = mint.key();
+    stake_pool.vault = ctx.accounts.vault.key();
+    stake_pool.total_staked = 0;
+    stake_pool.reward_per_share = 0;
+    stake_pool.total_harvested = 0;
+    stake_pool.total_claimed = 0;
+    stake_pool.reward_duration = reward_duration;
+    stake_pool.undistributed = 0;
+    stake_pool.last_release = current_time;
+    stake_pool.release_end = current_time;
+    stake_pool.harvest_sources = Vec::new();
+    stake_pool.bump = ctx.bumps.stake_pool;
+
+    // The vault is owned by the pool and never decays, its snapshot tracks `total_staked`
+    let vault = &mut ctx.accounts.vault;
+    vault.mint = mint.key();
+    vault.owner = stake_pool.key();
+    vault.state = AccountState::Vault;
+    vault.creation_time = current_time;
+    vault.last_update_time = current_time;
+    vault.last_balance_snapshot = 0;
+    vault.current_chrono_equation = mint.chrono_equation;
+
+    let vault_decay_pool = &mut ctx.accounts.vault_decay_pool;
+    vault_decay_pool.token_account = vault.key();
+    vault_decay_pool.amount = 0;
+    vault_decay_pool.bump = ctx.bumps.vault_decay_pool;
+
+    Ok(())
+}
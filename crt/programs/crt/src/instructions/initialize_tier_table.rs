@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TierTable, Tier};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+
+#[derive(Accounts)]
+pub struct InitializeTierTable<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        init,
+        payer = payer,
+        space = TierTable::LEN,
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump
+    )]
+    pub tier_table: Account<'info, TierTable>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTierTable>, mut tiers: Vec<Tier>) -> Result<()> {
+    let mint = &ctx.accounts.mint;
+
+    if mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+
+    TierTable::validate(&mut tiers, ctx.accounts.chrono_extension.time_base)?;
+
+    let tier_table = &mut ctx.accounts.tier_table;
+    tier_table.mint = mint.key();
+    tier_table.tiers = tiers;
+    tier_table.bump = ctx.bumps.tier_table;
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{TokenAccount, Mint, DecayPool, AccountState, ChronoEquationType};
+use crate::state::{TokenAccount, Mint, DecayPool, AccountState};
+use crate::extensions::ChronoExtension;
 
 
 #[derive(Accounts)]
@@ -15,6 +16,11 @@ pub struct InitializeTokenAccount<'info> {
     )]
     pub decay_pool: Account<'info, DecayPool>,
     pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -28,24 +34,28 @@ pub fn handler(
     let token_account = &mut ctx.accounts.token_account;
     let decay_pool = &mut ctx.accounts.decay_pool;
     let mint = &mut ctx.accounts.mint;
-    let clock = Clock::get()?;
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
 
     token_account.mint = mint.key();
     token_account.owner = ctx.accounts.authority.key();
 
-    token_account.delegate = delegate.unwrap_or(Pubkey::default());
+    token_account.delegate = delegate.unwrap_or_default();
     token_account.state = AccountState::Initialized;
-    token_account.creation_time = clock.unix_timestamp;
+    token_account.creation_time = current_time;
+    token_account.last_update_time = current_time;
     token_account.last_balance_snapshot = 0;
 
     // should be pda for the token account owned by the chrono program
     decay_pool.token_account = token_account.key();
     decay_pool.amount = 0;
+    decay_pool.bump = ctx.bumps.decay_pool;
 
 
     token_account.current_chrono_equation = mint.chrono_equation;
 
     token_account.delegated_amount = 0;
     token_account.close_authority = Some(ctx.accounts.authority.key());
+    token_account.last_reup_time = 0;
+    token_account.reup_streak = 0;
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use crate::state::Mint;
+use crate::error::TokenError;
+use crate::extensions::{ChronoExtension, LegacyChronoExtension};
+
+/// Rewrites an extension created before the canonical bump was stored into the current layout,
+/// so the `bump = chrono_extension.bump` seeds checks pass for pre-existing mints
+#[derive(Accounts)]
+pub struct MigrateChronoExtension<'info> {
+    pub mint: Account<'info, Mint>,
+    /// CHECK: Read with the legacy layout in the handler, its seeds and owner are checked here
+    #[account(
+        mut,
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub chrono_extension: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateChronoExtension>) -> Result<()> {
+    if ctx.accounts.mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+
+    let info = ctx.accounts.chrono_extension.to_account_info();
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        // Only extensions still at their original size carry the legacy layout
+        if data.len() != LegacyChronoExtension::LEN || data[..8] != ChronoExtension::DISCRIMINATOR {
+            return Err(TokenError::InvalidAccountData.into());
+        }
+        LegacyChronoExtension::deserialize(&mut &data[8..])?
+    };
+
+    let extension = ChronoExtension::new(
+        legacy.authority,
+        legacy.program_id,
+        legacy.equation_type,
+        legacy.pause_type,
+        legacy.equation_params.into(),
+        legacy.reup_percentage,
+        ctx.bumps.chrono_extension,
+        None,
+    );
+
+    let rent = Rent::get()?.minimum_balance(ChronoExtension::LEN);
+    let shortfall = rent.saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    info.realloc(ChronoExtension::LEN, true)?;
+
+    let mut data = info.try_borrow_mut_data()?;
+    extension.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{TokenAccount, DecayPool};
+use crate::error::TokenError;
+
+/// Repairs decay pools created before the canonical bump was stored on initialization,
+/// and starts settlement tracking on token accounts created before it existed
+#[derive(Accounts)]
+pub struct MigrateDecayPool<'info> {
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+}
+
+pub fn handler(ctx: Context<MigrateDecayPool>) -> Result<()> {
+    let token_account = &mut ctx.accounts.token_account;
+    let decay_pool = &mut ctx.accounts.decay_pool;
+
+    if decay_pool.token_account != token_account.key() {
+        return Err(TokenError::InvalidAccountData.into());
+    }
+
+    decay_pool.bump = ctx.bumps.decay_pool;
+
+    // Older accounts evaluated their snapshot from creation, the appended fields read as zero
+    if token_account.last_update_time == 0 {
+        token_account.last_update_time = token_account.creation_time;
+        token_account.floor_basis = token_account.last_balance_snapshot;
+    }
+
+    Ok(())
+}
@@ -1,42 +1,94 @@
 use anchor_lang::prelude::*;
-use crate::state::{Mint, TokenAccount};
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, ChronoEquationType};
 use crate::error::TokenError;
 use crate::events::MintToEvent;
-use crate::utils::evaluate_balance;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, settle, CurveConfig};
+use crate::extensions::ChronoExtension;
 
 #[derive(Accounts)]
 pub struct MintTo<'info> {
     #[account(mut)]
     pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
     #[account(mut)]
     pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+    /// CHECK: Owner of `token_account`, only read for owner program exemptions
+    #[account(address = token_account.owner)]
+    pub owner: Option<UncheckedAccount<'info>>,
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<MintTo>, amount: u64) -> Result<()> {
     let mint = &mut ctx.accounts.mint;
     let token_account = &mut ctx.accounts.token_account;
-    let current_time = Clock::get()?.unix_timestamp;
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    if token_account.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        token_account,
+        ctx.accounts.owner.as_deref()
+    )?;
 
     // Check mint authority
     if mint.mint_authority != ctx.accounts.authority.key() {
         return Err(TokenError::InvalidMintAuthority.into());
     }
 
-    // Update mint supply
-    mint.supply = mint.supply.checked_add(amount).ok_or(TokenError::Overflow)?;
+    // Update mint supply, within the supply cap and emission allowance
+    mint.issue(amount, current_time)?;
 
     // Update token account balance formula
-    let current_balance = evaluate_balance(
-        token_account.last_balance_snapshot,
-        &token_account.current_chrono_equation,
-        token_account.creation_time,
-        current_time)?;
+    let current_balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
 
     let new_balance = current_balance.checked_add(amount).ok_or(TokenError::Overflow)?;
 
-    // Create a new balance formula that adds the minted amount based on the equation type
-    token_account.last_balance_snapshot = new_balance;
+    // Decay realized since the last update goes to the account's pool
+    settle(token_account, &mut ctx.accounts.decay_pool, current_balance, new_balance, &config, current_time)?;
+
+    // Tokens minted into a vesting account are granted under the mint's vesting schedule
+    if token_account.current_chrono_equation == ChronoEquationType::Vesting {
+        token_account.vesting_total = token_account.vesting_total.checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+    }
 
     // Emit an event for the mint
     emit!(MintToEvent {
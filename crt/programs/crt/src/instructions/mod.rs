@@ -1,15 +1,98 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize_mint;
 pub mod initialize_token_account;
 pub mod transfer;
+pub mod transfer_checked;
+pub mod batch_transfer;
 pub mod mint_to;
 pub mod burn;
 pub mod pause_decay;
 pub mod reup;
+pub mod migrate_decay_pool;
+pub mod migrate_chrono_extension;
+pub mod create_subscription_plan;
+pub mod renew_subscription;
+pub mod subscribe;
+pub mod unsubscribe;
+pub mod collect_subscription;
+pub mod cancel_subscription;
+pub mod withdraw_subscription_escrow;
+pub mod get_balance;
+pub mod initialize_schedule;
+pub mod initialize_tier_table;
+pub mod set_account_equation;
+pub mod initialize_exemption_registry;
+pub mod add_exemption;
+pub mod remove_exemption;
+pub mod initialize_fee_vault;
+pub mod withdraw_withheld_fees;
+pub mod emit_scheduled;
+pub mod pause_mint;
+pub mod resume_mint;
+pub mod create_distributor;
+pub mod claim;
+pub mod clawback_distributor;
+pub mod open_snapshot;
+pub mod record_snapshot_balance;
+pub mod get_snapshot_balance;
+pub mod create_escrow;
+pub mod release_escrow;
+pub mod refund_escrow;
+pub mod initialize_stake_pool;
+pub mod open_stake_position;
+pub mod stake;
+pub mod unstake;
+pub mod claim_stake_rewards;
+pub mod harvest_decay;
+pub mod create_stream;
+pub mod withdraw_stream;
+pub mod cancel_stream;
+pub mod add_harvest_source;
+pub mod remove_harvest_source;
 
 pub use initialize_mint::*;
 pub use initialize_token_account::*;
 pub use transfer::*;
+pub use batch_transfer::*;
 pub use mint_to::*;
 pub use burn::*;
 pub use reup::*;
-pub use pause_decay::*;
\ No newline at end of file
+pub use pause_decay::*;
+pub use migrate_decay_pool::*;
+pub use migrate_chrono_extension::*;
+pub use create_subscription_plan::*;
+pub use renew_subscription::*;
+pub use subscribe::*;
+pub use unsubscribe::*;
+pub use collect_subscription::*;
+pub use cancel_subscription::*;
+pub use withdraw_subscription_escrow::*;
+pub use get_balance::*;
+pub use initialize_schedule::*;
+pub use initialize_tier_table::*;
+pub use set_account_equation::*;
+pub use initialize_exemption_registry::*;
+pub use add_exemption::*;
+pub use remove_exemption::*;
+pub use initialize_fee_vault::*;
+pub use withdraw_withheld_fees::*;
+pub use emit_scheduled::*;
+pub use pause_mint::*;
+pub use resume_mint::*;
+pub use create_distributor::*;
+pub use claim::*;
+pub use clawback_distributor::*;
+pub use open_snapshot::*;
+pub use record_snapshot_balance::*;
+pub use get_snapshot_balance::*;
+pub use create_escrow::*;
+pub use release_escrow::*;
+pub use initialize_stake_pool::*;
+pub use open_stake_position::*;
+pub use stake::*;
+pub use harvest_decay::*;
+pub use create_stream::*;
+pub use withdraw_stream::*;
+pub use cancel_stream::*;
+pub use add_harvest_source::*;
\ No newline at end of file
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, Snapshot};
+use crate::error::TokenError;
+use crate::events::SnapshotOpenedEvent;
+use crate::extensions::ChronoExtension;
+
+#[derive(Accounts)]
+#[instruction(snapshot_id: u64)]
+pub struct OpenSnapshot<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        init,
+        payer = payer,
+        space = Snapshot::LEN,
+        seeds = [b"snapshot", mint.key().as_ref(), &snapshot_id.to_le_bytes()],
+        bump
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<OpenSnapshot>,
+    snapshot_id: u64,
+    snapshot_time: i64,
+    record_deadline: i64,
+) -> Result<()> {
+    let mint = &ctx.accounts.mint;
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+
+    if mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+    // Balances settled before the snapshot opens are past reconstruction
+    if snapshot_time < current_time || record_deadline <= snapshot_time {
+        return Err(TokenError::InvalidSnapshot.into());
+    }
+    // Accounts hold a single checkpoint, so recording windows cannot overlap
+    let extension = &mut ctx.accounts.chrono_extension;
+    if extension.snapshot_time.is_some() && current_time <= extension.snapshot_deadline {
+        msg!("The previous snapshot records until {}", extension.snapshot_deadline);
+        return Err(TokenError::InvalidSnapshot.into());
+    }
+    extension.snapshot_time = Some(snapshot_time);
+    extension.snapshot_deadline = record_deadline;
+
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.mint = mint.key();
+    snapshot.authority = ctx.accounts.authority.key();
+    snapshot.snapshot_id = snapshot_id;
+    snapshot.snapshot_time = snapshot_time;
+    snapshot.record_deadline = record_deadline;
+    snapshot.recorded_accounts = 0;
+    snapshot.recorded_total = 0;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    emit!(SnapshotOpenedEvent {
+        mint: mint.key(),
+        snapshot: snapshot.key(),
+        snapshot_id,
+        snapshot_time,
+        record_deadline,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{TokenAccount, StakePool, StakePosition};
+use crate::error::TokenError;
+
+#[derive(Accounts)]
+pub struct OpenStakePosition<'info> {
+    #[account(
+        seeds = [b"stake_pool", stake_pool.mint.as_ref()],
+        bump = stake_pool.bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = owner,
+        space = StakePosition::LEN,
+        seeds = [b"stake_position", stake_pool.key().as_ref(), token_account.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenStakePosition>) -> Result<()> {
+    let token_account = &ctx.accounts.token_account;
+
+    if token_account.mint != ctx.accounts.stake_pool.mint {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if token_account.owner != ctx.accounts.owner.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    let stake_position = &mut ctx.accounts.stake_position;
+    stake_position.stake_pool = ctx.accounts.stake_pool.key();
+    stake_position.token_account = token_account.key();
+    stake_position.owner = token_account.owner;
+    stake_position.amount = 0;
+    stake_position.reward_debt = 0;
+    stake_position.pending_rewards = 0;
+    stake_position.bump = ctx.bumps.stake_position;
+
+    Ok(())
+}
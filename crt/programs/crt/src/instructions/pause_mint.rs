@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, DecayPause};
+use crate::error::TokenError;
+use crate::events::MintPausedEvent;
+use crate::extensions::ChronoExtension;
+
+/// Halts transfers, mints, burns and ReUps on the mint, optionally stopping decay too
+#[derive(Accounts)]
+#[instruction(stop_decay: bool)]
+pub struct PauseMint<'info> {
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump,
+        realloc = ChronoExtension::space(chrono_extension.decay_pauses.len() + stop_decay as usize),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    pub authority: Signer<'info>,
+    /// Funds the extension's growth when the pause stops decay
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PauseMint>, stop_decay: bool) -> Result<()> {
+    let mint = &mut ctx.accounts.mint;
+    let extension = &mut ctx.accounts.chrono_extension;
+    let current_time = extension.time_base.now(&Clock::get()?);
+
+    if mint.pause_authority != Some(ctx.accounts.authority.key()) {
+        return Err(TokenError::InvalidPauseAuthority.into());
+    }
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+
+    if stop_decay {
+        extension.decay_pauses.push(DecayPause { paused_at: current_time, resumed_at: None });
+    }
+    mint.paused = true;
+
+    emit!(MintPausedEvent {
+        mint: mint.key(),
+        authority: ctx.accounts.authority.key(),
+        stop_decay,
+    });
+
+    Ok(())
+}
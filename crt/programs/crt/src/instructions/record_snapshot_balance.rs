@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, EquationSchedule, TierTable, ExemptionRegistry, Snapshot, BalanceRecord};
+use crate::error::TokenError;
+use crate::events::BalanceRecordedEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, CurveConfig};
+
+/// Records an account's balance at the snapshot time, callable by the holder or any keeper.
+/// Accounts settled since the snapshot time are read from the checkpoint that settlement took.
+#[derive(Accounts)]
+pub struct RecordSnapshotBalance<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"snapshot", mint.key().as_ref(), &snapshot.snapshot_id.to_le_bytes()],
+        bump = snapshot.bump
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+    pub token_account: Account<'info, TokenAccount>,
+    /// CHECK: Owner of `token_account`, only read for owner program exemptions
+    #[account(address = token_account.owner)]
+    pub owner: Option<UncheckedAccount<'info>>,
+    #[account(
+        init,
+        payer = payer,
+        space = BalanceRecord::LEN,
+        seeds = [b"balance_record", snapshot.key().as_ref(), token_account.key().as_ref()],
+        bump
+    )]
+    pub balance_record: Account<'info, BalanceRecord>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordSnapshotBalance>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    let snapshot = &mut ctx.accounts.snapshot;
+    let token_account = &ctx.accounts.token_account;
+
+    if token_account.mint != ctx.accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if current_time < snapshot.snapshot_time || current_time > snapshot.record_deadline {
+        return Err(TokenError::SnapshotWindowClosed.into());
+    }
+    let balance = if token_account.last_update_time > snapshot.snapshot_time {
+        // A settlement after the snapshot time checkpointed the balance it had then
+        if token_account.checkpoint_time != snapshot.snapshot_time {
+            return Err(TokenError::SnapshotBalanceUnavailable.into());
+        }
+        token_account.checkpoint_balance
+    } else {
+        let exempt = is_exempt(
+            &ctx.accounts.chrono_extension,
+            ctx.accounts.exemption_registry.as_deref(),
+            token_account,
+            ctx.accounts.owner.as_deref()
+        )?;
+        evaluate_balance_unless_exempt(token_account, exempt, &config, snapshot.snapshot_time)?
+    };
+
+    snapshot.recorded_accounts = snapshot.recorded_accounts.checked_add(1)
+        .ok_or(TokenError::Overflow)?;
+    snapshot.recorded_total = snapshot.recorded_total.checked_add(balance)
+        .ok_or(TokenError::Overflow)?;
+
+    let balance_record = &mut ctx.accounts.balance_record;
+    balance_record.snapshot = snapshot.key();
+    balance_record.token_account = token_account.key();
+    balance_record.owner = token_account.owner;
+    balance_record.balance = balance;
+    balance_record.recorded_at = current_time;
+    balance_record.bump = ctx.bumps.balance_record;
+
+    emit!(BalanceRecordedEvent {
+        snapshot: snapshot.key(),
+        token_account: token_account.key(),
+        owner: token_account.owner,
+        balance,
+    });
+
+    Ok(())
+}
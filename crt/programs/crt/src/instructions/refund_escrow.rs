@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use super::release_escrow::{pay_out, ReleaseEscrow};
+
+/// `release_escrow` that returns the vault to the depositor instead
+pub fn handler(ctx: Context<ReleaseEscrow>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let escrow = &ctx.accounts.escrow;
+    let authority = ctx.accounts.authority.key();
+
+    // The arbiter settles the condition against the beneficiary, who can also waive the escrow
+    if authority != escrow.beneficiary && !escrow.is_arbiter(&authority) {
+        return Err(TokenError::InvalidEscrowAuthority.into());
+    }
+    if ctx.accounts.token_account.owner != escrow.depositor {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    pay_out(ctx.accounts, current_time, true)
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, Escrow};
+use crate::error::TokenError;
+use crate::events::EscrowReleasedEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{settle_transfer, evaluate_balance_unless_exempt, is_exempt, CurveConfig};
+
+/// Pays the whole vault out to the beneficiary (`release_escrow`) or back to the depositor
+/// (`refund_escrow`). Whatever the vault decayed to is what gets paid, the decay a shared
+/// vault realized goes to the depositor's pool, `depositor_decay_pool` on release and the
+/// refunded account's pool on refund.
+#[derive(Accounts)]
+pub struct ReleaseEscrow<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"escrow", mint.key().as_ref(), escrow.depositor.as_ref(), &escrow.escrow_id.to_le_bytes()],
+        bump = escrow.bump,
+        has_one = vault @ TokenError::InvalidEscrow
+    )]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", vault.key().as_ref()],
+        bump = vault_decay_pool.bump
+    )]
+    pub vault_decay_pool: Account<'info, DecayPool>,
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+    /// CHECK: Owner of `token_account`, only read for owner program exemptions
+    #[account(address = token_account.owner)]
+    pub owner: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", escrow.depositor_token_account.as_ref()],
+        bump = depositor_decay_pool.bump
+    )]
+    pub depositor_decay_pool: Option<Account<'info, DecayPool>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseEscrow>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let escrow = &ctx.accounts.escrow;
+    let authority = ctx.accounts.authority.key();
+
+    // The beneficiary waits out the lock, the depositor and arbiter can release early
+    let unlocked = authority == escrow.beneficiary && current_time >= escrow.unlock_time;
+    if !unlocked && authority != escrow.depositor && !escrow.is_arbiter(&authority) {
+        if authority == escrow.beneficiary {
+            return Err(TokenError::EscrowLocked.into());
+        }
+        return Err(TokenError::InvalidEscrowAuthority.into());
+    }
+    if ctx.accounts.token_account.owner != escrow.beneficiary {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    pay_out(ctx.accounts, current_time, false)
+}
+
+/// Shared by `release_escrow` and `refund_escrow`
+pub(crate) fn pay_out(accounts: &mut ReleaseEscrow, current_time: i64, refunded: bool) -> Result<()> {
+    let config = CurveConfig::load(
+        &accounts.chrono_extension,
+        accounts.schedule.as_deref(),
+        accounts.tier_table.as_deref()
+    )?;
+    let escrow = &mut accounts.escrow;
+
+    if escrow.settled {
+        return Err(TokenError::EscrowSettled.into());
+    }
+    if accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if accounts.token_account.mint != accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if accounts.token_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // An exempt vault is held flat by its vault state
+    let vault_exempt = is_exempt(
+        &accounts.chrono_extension,
+        accounts.exemption_registry.as_deref(),
+        &accounts.vault,
+        Some(&escrow.to_account_info())
+    )?;
+    let to_exempt = is_exempt(
+        &accounts.chrono_extension,
+        accounts.exemption_registry.as_deref(),
+        &accounts.token_account,
+        accounts.owner.as_deref()
+    )?;
+    let amount = evaluate_balance_unless_exempt(&accounts.vault, vault_exempt, &config, current_time)?;
+
+    settle_transfer(
+        &mut accounts.vault,
+        &mut accounts.vault_decay_pool,
+        &mut accounts.token_account,
+        &mut accounts.decay_pool,
+        amount,
+        (vault_exempt, to_exempt),
+        &config,
+        current_time
+    )?;
+    escrow.settled = true;
+
+    // The depositor bore a shared vault's decay, it is theirs to ReUp
+    if !vault_exempt {
+        let depositor_decay_pool = if refunded {
+            &mut accounts.decay_pool
+        } else {
+            accounts.depositor_decay_pool.as_mut().ok_or(TokenError::InvalidDecayPool)?
+        };
+        depositor_decay_pool.amount = depositor_decay_pool.amount.checked_add(accounts.vault_decay_pool.amount)
+            .ok_or(TokenError::Overflow)?;
+        accounts.vault_decay_pool.amount = 0;
+    }
+
+    emit!(EscrowReleasedEvent {
+        escrow: escrow.key(),
+        token_account: accounts.token_account.key(),
+        authority: accounts.authority.key(),
+        amount,
+        refunded,
+    });
+
+    Ok(())
+}
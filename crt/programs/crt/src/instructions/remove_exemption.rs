@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, EquationSchedule, TierTable, ExemptionRegistry, ExemptionKind};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+use crate::utils::CurveConfig;
+use super::add_exemption::settle_affected;
+
+/// `remaining_accounts` holds the affected (token account, decay pool, owner) triples, as for `AddExemption`
+#[derive(Accounts)]
+pub struct RemoveExemption<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        mut,
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Account<'info, ExemptionRegistry>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RemoveExemption<'info>>,
+    kind: ExemptionKind,
+    key: Pubkey,
+) -> Result<()> {
+    if ctx.accounts.mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    settle_affected(
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.exemption_registry,
+        &config,
+        ctx.program_id,
+        ctx.remaining_accounts,
+        kind,
+        &key,
+        current_time
+    )?;
+
+    let entries = ctx.accounts.exemption_registry.entries_mut(kind);
+    let index = entries.iter().position(|entry| *entry == key)
+        .ok_or(TokenError::NotExempt)?;
+    entries.swap_remove(index);
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use super::add_harvest_source::SetHarvestSource;
+
+/// Opts a token account's decay pool out of harvesting, by its owner or the mint authority
+pub fn handler(ctx: Context<SetHarvestSource>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    if ctx.accounts.token_account.owner != authority && ctx.accounts.mint.mint_authority != authority {
+        return Err(TokenError::InvalidAuthority.into());
+    }
+
+    let token_account = ctx.accounts.token_account.key();
+    let harvest_sources = &mut ctx.accounts.stake_pool.harvest_sources;
+    let index = harvest_sources.iter().position(|source| *source == token_account)
+        .ok_or(TokenError::NotHarvestable)?;
+    harvest_sources.swap_remove(index);
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    Mint, TokenAccount, DecayPool, SubscriptionPlan, EquationSchedule, TierTable, ExemptionRegistry, FeeVault,
+};
+use crate::error::TokenError;
+use crate::events::SubscriptionRenewedEvent;
+use crate::utils::{is_exempt, settle_transfer, CurveConfig};
+use crate::extensions::ChronoExtension;
+use super::transfer::withhold_fee;
+
+#[derive(Accounts)]
+pub struct RenewSubscription<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        seeds = [b"subscription_plan", mint.key().as_ref(), plan.merchant.as_ref(), &plan.plan_id.to_le_bytes()],
+        bump = plan.bump,
+        has_one = mint @ TokenError::MintMismatch,
+        has_one = merchant_token_account @ TokenError::SubscriptionPlanMismatch
+    )]
+    pub plan: Account<'info, SubscriptionPlan>,
+    #[account(mut)]
+    pub subscriber: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", subscriber.key().as_ref()],
+        bump = subscriber_decay_pool.bump
+    )]
+    pub subscriber_decay_pool: Account<'info, DecayPool>,
+    #[account(mut)]
+    pub merchant_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", merchant_token_account.key().as_ref()],
+        bump = merchant_decay_pool.bump
+    )]
+    pub merchant_decay_pool: Account<'info, DecayPool>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+    /// CHECK: Owner of `merchant_token_account`, only read for owner program exemptions
+    #[account(address = merchant_token_account.owner)]
+    pub merchant_owner: Option<UncheckedAccount<'info>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RenewSubscription>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    let mint = &ctx.accounts.mint;
+    let plan = &ctx.accounts.plan;
+    let subscriber = &mut ctx.accounts.subscriber;
+    let merchant = &mut ctx.accounts.merchant_token_account;
+
+    if subscriber.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if subscriber.owner != ctx.accounts.authority.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+    if subscriber.cancelled_plan == plan.key() {
+        return Err(TokenError::SubscriptionCancelled.into());
+    }
+    if subscriber.is_frozen() || merchant.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if subscriber.key() == merchant.key() {
+        return Err(TokenError::SelfTransfer.into());
+    }
+
+    // Switching plans starts a fresh renewal count
+    let renewals = if subscriber.subscription_plan == plan.key() {
+        subscriber.subscription_renewals
+    } else {
+        0
+    };
+    if plan.max_renewals != 0 && renewals >= plan.max_renewals {
+        return Err(TokenError::MaxRenewalsReached.into());
+    }
+
+    // Renewals are transfers, exemptions and the mint's fee apply the same way
+    let extension = &ctx.accounts.chrono_extension;
+    let registry = ctx.accounts.exemption_registry.as_deref();
+    let exempt = (
+        is_exempt(extension, registry, subscriber, Some(&ctx.accounts.authority.to_account_info()))?,
+        is_exempt(extension, registry, merchant, ctx.accounts.merchant_owner.as_deref())?,
+    );
+
+    settle_transfer(
+        subscriber,
+        &mut ctx.accounts.subscriber_decay_pool,
+        merchant,
+        &mut ctx.accounts.merchant_decay_pool,
+        plan.price,
+        exempt,
+        &config,
+        current_time
+    )?;
+    withhold_fee(
+        extension,
+        plan.price,
+        merchant,
+        &mut ctx.accounts.subscriber_decay_pool,
+        ctx.accounts.fee_vault.as_deref_mut()
+    )?;
+
+    // Renewing early extends the current period, renewing after it ended starts a new one.
+    // Time already paid on a previous plan carries over to the new one.
+    let period_start = subscriber.subscription_expiry.max(current_time);
+    if subscriber.subscription_plan != plan.key() {
+        // Collection consent belongs to the previous plan
+        subscriber.subscription_auto_renew = false;
+        subscriber.subscription_carried_until = subscriber.subscription_expiry;
+    }
+
+    subscriber.subscription_plan = plan.key();
+    subscriber.subscription_expiry = period_start.checked_add(plan.period)
+        .ok_or(TokenError::Overflow)?;
+    subscriber.subscription_grace_period = plan.grace_period;
+    subscriber.subscription_renewals = renewals.checked_add(1)
+        .ok_or(TokenError::Overflow)?;
+
+    emit!(SubscriptionRenewedEvent {
+        mint: mint.key(),
+        plan: plan.key(),
+        token_account: subscriber.key(),
+        price: plan.price,
+        expiry: subscriber.subscription_expiry,
+        renewals: subscriber.subscription_renewals,
+    });
+
+    Ok(())
+}
+
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::Mint;
+use crate::error::TokenError;
+use crate::events::MintResumedEvent;
+use crate::extensions::ChronoExtension;
+
+#[derive(Accounts)]
+pub struct ResumeMint<'info> {
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResumeMint>) -> Result<()> {
+    let mint = &mut ctx.accounts.mint;
+    let extension = &mut ctx.accounts.chrono_extension;
+    let current_time = extension.time_base.now(&Clock::get()?);
+
+    if mint.pause_authority != Some(ctx.accounts.authority.key()) {
+        return Err(TokenError::InvalidPauseAuthority.into());
+    }
+    if !mint.paused {
+        return Err(TokenError::MintNotPaused.into());
+    }
+
+    mint.paused = false;
+    // Closes the open decay pause, curves skip it from now on
+    if let Some(pause) = extension.decay_pauses.last_mut().filter(|pause| pause.resumed_at.is_none()) {
+        pause.resumed_at = Some(current_time);
+    }
+
+    emit!(MintResumedEvent {
+        mint: mint.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -1,16 +1,36 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::program::invoke;
-use crate::state::{Mint, TokenAccount, AccountState, DecayPool, PauseType};
+use crate::state::{Mint, TokenAccount, AccountState, DecayPool, PauseType, EquationSchedule, TierTable, ExemptionRegistry};
 use crate::error::TokenError;
 use crate::events::ReUpEvent;
 use crate::extensions::ChronoExtension;
-use crate::utils::evaluate_balance;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, realized_decay, settle, CurveConfig};
 
 #[derive(Accounts)]
 pub struct ReUp<'info> {
     #[account(mut)]
     pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
     #[account(mut)]
     pub token_account: Account<'info, TokenAccount>,
     #[account(mut)]
@@ -24,106 +44,127 @@ pub struct ReUp<'info> {
 
 pub fn handler(ctx: Context<ReUp>) -> Result<()> {
     let mint = &ctx.accounts.mint;
+    let extension = &ctx.accounts.chrono_extension;
     let token_account = &mut ctx.accounts.token_account;
     let decay_pool = &mut ctx.accounts.decay_pool;
-    let clock = Clock::get()?;
-    let current_time = clock.unix_timestamp;
-
-    //////////////////////////////////////////////////////
-    let binding = mint.to_account_info();
-    let mint_data = binding.try_borrow_data()?;
-    if mint_data.len() > Mint::LEN {
-        let extension_data = &mint_data[Mint::LEN..];
-        if let Ok(extension) = ChronoExtension::try_from_slice(extension_data) {
-            // Verify the provided ReUp hook program matches the one in the extension
-            if extension.program_id != ctx.accounts.chrono_hook_program.key() {
-                return Err(ProgramError::InvalidAccountData.into());
-            }
-
-            // Check if pause is allowed
-            if extension.pause_type != PauseType::ReUp {
-                return Err(TokenError::ReUpNotAllowed.into());
-            }
-
-            // Ensure the token account is not paused
-            if token_account.state == AccountState::Pause {
-                return Err(TokenError::AccountFrozen.into());
-            }
-
-            // Ensure the authority is correct
-            if token_account.owner != ctx.accounts.authority.key() {
-                return Err(TokenError::OwnerMismatch.into());
-            }
-
-
-            // Call the chrono program using invoke
-            let accounts = vec![
-                AccountMeta::new(mint.key(), false),
-                AccountMeta::new(token_account.key(), false),
-                AccountMeta::new_readonly(ctx.accounts.authority.key(), true),
-            ];
-
-            let instruction = Instruction {
-                program_id: extension.program_id,
-                accounts,
-                data: AnchorSerialize::try_to_vec(&0u64)?, // Passing 0 as dummy data
-            };
-
-            invoke(&instruction, &[
-                mint.to_account_info(),
-                token_account.to_account_info(),
-                ctx.accounts.authority.to_account_info(),
-            ])?;
-
-            //Re up logic here
-
-            //reup percentage gotten from mint account
-            let reup_percentage = extension.reup_percentage;
-            // Ensure reup_percentage is valid (0-100)
-            if reup_percentage > 100 {
-                return Err(TokenError::InvalidReUpPercentage.into());
-            }
-
-            // Calculate the amount to ReUp from the decay pool
-            let reup_amount = (decay_pool.amount as u128 * reup_percentage as u128 / 100) as u64;
-
-            // Get the current balance
-            let current_balance = evaluate_balance(
-                token_account.last_balance_snapshot,
-                &token_account.current_chrono_equation,
-                token_account.creation_time,
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    if token_account.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    if mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+
+    // Verify the provided ReUp hook program matches the one in the extension
+    if extension.program_id != ctx.accounts.chrono_hook_program.key() {
+        return Err(ProgramError::InvalidAccountData.into());
+    }
+
+    // Check if pause is allowed
+    if extension.pause_type != PauseType::ReUp {
+        return Err(TokenError::ReUpNotAllowed.into());
+    }
+
+    // Ensure the token account is not paused
+    if token_account.state == AccountState::Pause {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // Ensure the authority is correct
+    if token_account.owner != ctx.accounts.authority.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Without a policy every ReUp pays the flat percentage and extends the streak
+    let (reup_percentage, reup_streak) = match extension.reup_policy {
+        Some(policy) => {
+            let streak = policy.next_streak(
+                token_account.last_reup_time,
+                token_account.reup_streak,
                 current_time
             )?;
-
-            // Apply the ReUp boost
-            let new_balance = current_balance.checked_add(reup_amount)
-                .ok_or(TokenError::Overflow)?;
-
-            // Update the token account
-            token_account.last_balance_snapshot = new_balance;
-
-            //decay pool token account should match signers mint token account
-            //SAFE by virtue of token owner check on ln 52
-            if token_account.key() != decay_pool.token_account {
-                return Err(TokenError::InvalidAuthority.into())
-            }
-
-            // Update the decay pool
-            decay_pool.amount = decay_pool.amount.checked_sub(reup_amount)
-                .ok_or(TokenError::InsufficientFunds)?;
-
-            // Emit a ReUp event
-            emit!(ReUpEvent {
-                    mint: mint.key(),
-                    token_account: token_account.key(),
-                    authority: ctx.accounts.authority.key(),
-                    amount: reup_amount,
-                    new_balance,
-                    decay_pool_balance: decay_pool.amount,
-               });
+            (policy.percentage_for(streak), streak)
         }
+        None => (extension.reup_percentage, token_account.reup_streak.saturating_add(1)),
+    };
+
+    // Call the chrono program using invoke
+    let accounts = vec![
+        AccountMeta::new(mint.key(), false),
+        AccountMeta::new(token_account.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.authority.key(), true),
+    ];
+
+    let instruction = Instruction {
+        program_id: extension.program_id,
+        accounts,
+        data: AnchorSerialize::try_to_vec(&0u64)?, // Passing 0 as dummy data
+    };
+
+    invoke(&instruction, &[
+        mint.to_account_info(),
+        token_account.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+    ])?;
+
+    // Ensure reup_percentage is valid (0-100)
+    if reup_percentage > 100 {
+        return Err(TokenError::InvalidReUpPercentage.into());
     }
 
+    //decay pool token account should match signers mint token account
+    //SAFE by virtue of the token owner check above
+    if token_account.key() != decay_pool.token_account {
+        return Err(TokenError::InvalidAuthority.into())
+    }
+
+    // Get the current balance, the owner signs so it doubles as the exemption owner
+    let exempt = is_exempt(
+        extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        token_account,
+        Some(&ctx.accounts.authority.to_account_info())
+    )?;
+    let current_balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
+
+    // Decay realized since the last update lands in the pool first, so it can be ReUp'd too
+    let available = decay_pool.amount
+        .checked_add(realized_decay(token_account.last_balance_snapshot, current_balance))
+        .ok_or(TokenError::Overflow)?;
+
+    // Calculate the amount to ReUp from the decay pool
+    let reup_amount = (available as u128 * reup_percentage as u128 / 100) as u64;
+
+    // Apply the ReUp boost
+    let new_balance = current_balance.checked_add(reup_amount)
+        .ok_or(TokenError::Overflow)?;
+
+    // Update the token account
+    settle(token_account, decay_pool, current_balance, new_balance, &config, current_time)?;
+    token_account.last_reup_time = current_time;
+    token_account.reup_streak = reup_streak;
+
+    // Update the decay pool
+    decay_pool.amount = decay_pool.amount.checked_sub(reup_amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+
+    // Emit a ReUp event
+    emit!(ReUpEvent {
+        mint: mint.key(),
+        token_account: token_account.key(),
+        authority: ctx.accounts.authority.key(),
+        amount: reup_amount,
+        new_balance,
+        decay_pool_balance: decay_pool.amount,
+        streak: reup_streak,
+    });
+
     Ok(())
 }
-
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry};
+use crate::error::TokenError;
+use crate::events::AccountEquationChangedEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, settle, CurveConfig};
+
+/// Moves an account onto one of the mint's tiers, or back onto the mint's own curve
+#[derive(Accounts)]
+pub struct SetAccountEquation<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+    /// CHECK: Owner of `token_account`, only read for owner program exemptions
+    #[account(address = token_account.owner)]
+    pub owner: Option<UncheckedAccount<'info>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAccountEquation>, tier: Option<u8>) -> Result<()> {
+    let mint = &ctx.accounts.mint;
+    let token_account = &mut ctx.accounts.token_account;
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    if mint.mint_authority != ctx.accounts.authority.key() {
+        return Err(TokenError::InvalidMintAuthority.into());
+    }
+    if token_account.mint != mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    let equation_type = match tier {
+        Some(index) => config.tier(index)?.equation_type,
+        None => mint.chrono_equation,
+    };
+
+    // Settle under the old curve so the new one only applies from now on
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        token_account,
+        ctx.accounts.owner.as_deref()
+    )?;
+    let balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
+    settle(token_account, &mut ctx.accounts.decay_pool, balance, balance, &config, current_time)?;
+
+    let previous_tier = token_account.tier;
+    token_account.tier = tier;
+    token_account.current_chrono_equation = equation_type;
+    token_account.curve_age = 0;
+
+    emit!(AccountEquationChangedEvent {
+        mint: mint.key(),
+        token_account: token_account.key(),
+        authority: ctx.accounts.authority.key(),
+        previous_tier,
+        tier,
+        equation_type,
+        settled_balance: balance,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, StakePool, StakePosition};
+use crate::error::TokenError;
+use crate::events::StakeEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, ensure_spendable, is_exempt, settle, CurveConfig};
+
+/// Accounts of `stake`, `unstake` and `claim_stake_rewards`. Staked tokens sit in the
+/// pool's vault with decay suspended until they are unstaked.
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"stake_pool", mint.key().as_ref()],
+        bump = stake_pool.bump,
+        has_one = vault @ TokenError::InvalidStakePosition
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", vault.key().as_ref()],
+        bump = vault_decay_pool.bump
+    )]
+    pub vault_decay_pool: Account<'info, DecayPool>,
+    #[account(
+        mut,
+        seeds = [b"stake_position", stake_pool.key().as_ref(), token_account.key().as_ref()],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", token_account.key().as_ref()],
+        bump = decay_pool.bump
+    )]
+    pub decay_pool: Account<'info, DecayPool>,
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Err(TokenError::InvalidAmount.into());
+    }
+
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    ctx.accounts.stake_pool.release(current_time)?;
+    let stake_position = &mut ctx.accounts.stake_position;
+    stake_position.accrue(&ctx.accounts.stake_pool)?;
+
+    let new_balance = settle_staker(ctx.accounts, 0, amount, current_time)?;
+    settle_vault(ctx.accounts, amount, 0, current_time)?;
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    stake_position.amount = stake_position.amount.checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    stake_position.reward_debt = stake_pool.rewards_for(stake_position.amount)?;
+    stake_pool.total_staked = stake_pool.total_staked.checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+
+    emit!(StakeEvent {
+        stake_pool: stake_pool.key(),
+        token_account: ctx.accounts.token_account.key(),
+        amount,
+        staked: stake_position.amount,
+        total_staked: stake_pool.total_staked,
+        new_balance,
+        unstaked: false,
+    });
+
+    Ok(())
+}
+
+/// Settles the pool's vault, which its vault state holds flat, moving `credit` into it and
+/// `debit` out of it
+pub(crate) fn settle_vault(accounts: &mut Stake, credit: u64, debit: u64, current_time: i64) -> Result<()> {
+    let config = CurveConfig::load(
+        &accounts.chrono_extension,
+        accounts.schedule.as_deref(),
+        accounts.tier_table.as_deref()
+    )?;
+    let exempt = is_exempt(
+        &accounts.chrono_extension,
+        accounts.exemption_registry.as_deref(),
+        &accounts.vault,
+        Some(&accounts.stake_pool.to_account_info())
+    )?;
+    let vault = &mut accounts.vault;
+
+    let balance = evaluate_balance_unless_exempt(vault, exempt, &config, current_time)?;
+    let new_balance = balance.checked_sub(debit)
+        .and_then(|balance| balance.checked_add(credit))
+        .ok_or(TokenError::Overflow)?;
+    settle(vault, &mut accounts.vault_decay_pool, balance, new_balance, &config, current_time)
+}
+
+/// Settles the staker's account, moving `debit` out of it and `credit` into it.
+/// Returns the account's new balance.
+pub(crate) fn settle_staker(accounts: &mut Stake, credit: u64, debit: u64, current_time: i64) -> Result<u64> {
+    let config = CurveConfig::load(
+        &accounts.chrono_extension,
+        accounts.schedule.as_deref(),
+        accounts.tier_table.as_deref()
+    )?;
+    let token_account = &mut accounts.token_account;
+
+    if token_account.mint != accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if token_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if token_account.owner != accounts.owner.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    let exempt = is_exempt(
+        &accounts.chrono_extension,
+        accounts.exemption_registry.as_deref(),
+        token_account,
+        Some(&accounts.owner.to_account_info())
+    )?;
+    let balance = evaluate_balance_unless_exempt(token_account, exempt, &config, current_time)?;
+    ensure_spendable(token_account, balance, debit, &config, current_time)?;
+
+    let new_balance = (balance - debit).checked_add(credit)
+        .ok_or(TokenError::Overflow)?;
+    settle(token_account, &mut accounts.decay_pool, balance, new_balance, &config, current_time)?;
+
+    Ok(new_balance)
+}
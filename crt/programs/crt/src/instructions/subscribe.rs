@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{TokenAccount, SubscriptionPlan};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+
+/// Opts a token account into merchant collection for a subscription plan
+#[derive(Accounts)]
+pub struct Subscribe<'info> {
+    #[account(
+        seeds = [b"subscription_plan", plan.mint.as_ref(), plan.merchant.as_ref(), &plan.plan_id.to_le_bytes()],
+        bump = plan.bump
+    )]
+    pub plan: Account<'info, SubscriptionPlan>,
+    #[account(
+        seeds = [b"chrono_extension", plan.mint.as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<Subscribe>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let plan = &ctx.accounts.plan;
+    let token_account = &mut ctx.accounts.token_account;
+
+    if token_account.mint != plan.mint {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if token_account.owner != ctx.accounts.authority.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+    if token_account.cancelled_plan == plan.key() {
+        return Err(TokenError::SubscriptionCancelled.into());
+    }
+
+    // Paid time carries over to a new plan. Without any, the merchant can collect the first period
+    // right away and the account stays live until then, unless its last subscription already lapsed
+    if token_account.subscription_plan != plan.key() {
+        let lapsed = token_account.subscription_cutoff().is_some_and(|cutoff| current_time > cutoff);
+        if token_account.subscription_expiry <= current_time && !lapsed {
+            token_account.subscription_expiry = 0;
+        }
+        token_account.subscription_carried_until = token_account.subscription_expiry;
+        token_account.subscription_plan = plan.key();
+        token_account.subscription_grace_period = plan.grace_period;
+        token_account.subscription_renewals = 0;
+    }
+    token_account.subscription_auto_renew = true;
+
+    Ok(())
+}
@@ -1,11 +1,33 @@
 use anchor_lang::prelude::*;
-use crate::state::{Mint, TokenAccount, DecayPool};
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, FeeVault, FeeDestination};
 use crate::error::TokenError;
-use crate::utils::evaluate_balance;
+use crate::utils::{is_exempt, realized_decay, settle_transfer, CurveConfig};
+use crate::events::TransferEvent;
+use crate::extensions::ChronoExtension;
 
 #[derive(Accounts)]
 pub struct Transfer<'info> {
     pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
     #[account(mut)]
     pub from: Account<'info, TokenAccount>,
     #[account(mut)]
@@ -22,17 +44,46 @@ pub struct Transfer<'info> {
         bump = to_decay_pool.bump
     )]
     pub to_decay_pool: Account<'info, DecayPool>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+    /// CHECK: Owner of `from`, only read for owner program exemptions
+    #[account(address = from.owner)]
+    pub from_owner: Option<UncheckedAccount<'info>>,
+    /// CHECK: Owner of `to`, only read for owner program exemptions
+    #[account(address = to.owner)]
+    pub to_owner: Option<UncheckedAccount<'info>>,
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<Transfer>, amount: u64) -> Result<()> {
-    let clock = Clock::get()?;
-    let current_time = clock.unix_timestamp;
+    process_transfer(ctx.accounts, amount)
+}
+
+/// Shared by `transfer` and `transfer_checked`
+pub(crate) fn process_transfer(accounts: &mut Transfer, amount: u64) -> Result<()> {
+    let current_time = accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &accounts.chrono_extension,
+        accounts.schedule.as_deref(),
+        accounts.tier_table.as_deref()
+    )?;
+
+    let sender = &mut accounts.from;
+    let receiver = &mut accounts.to;
+    let sender_decay_account = &mut accounts.from_decay_pool;
+    let receiver_decay_account = &mut accounts.to_decay_pool;
 
-    let sender = &mut ctx.accounts.from;
-    let receiver = &mut ctx.accounts.to;
-    let sender_decay_account = &mut ctx.accounts.to_decay_pool;
-    let receiver_decay_account = &mut ctx.accounts.from_decay_pool;
+    if sender.mint != accounts.mint.key() || receiver.mint != accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    if accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
 
     if sender.is_frozen() || receiver.is_frozen() {
         return Err(TokenError::AccountFrozen.into());
@@ -48,63 +99,69 @@ pub fn handler(ctx: Context<Transfer>, amount: u64) -> Result<()> {
         return Ok(());
     }
 
-    // Evaluate current balances
-    let from_balance = evaluate_balance(
-        sender.last_balance_snapshot,
-        &sender.current_chrono_equation,
-        sender.creation_time,
-        current_time
-    )?;
-
-    let to_balance = evaluate_balance(
-        receiver.last_balance_snapshot,
-        &receiver.current_chrono_equation,
-        receiver.creation_time,
-        current_time
-    )?;
-
-    // Check if sender has sufficient funds
-    if from_balance < amount {
-        return Err(TokenError::InsufficientFunds.into());
-    }
-
     // Check authority
-    // if ctx.accounts.from.owner != ctx.accounts.authority.key() {
-    //     if ctx.accounts.from.delegate != ctx.accounts.authority.key() {
+    // if accounts.from.owner != accounts.authority.key() {
+    //     if accounts.from.delegate != accounts.authority.key() {
     //         return Err(TokenError::InvalidAuthority.into());
     //     }
-    //     if ctx.accounts.from.delegated_amount < amount {
+    //     if accounts.from.delegated_amount < amount {
     //         return Err(TokenError::InsufficientDelegatedAmount.into());
     //     }
     // }
 
-    check_authority(sender, &ctx.accounts.authority.key(), amount)?;
-
-    // New paradigm how about we store the equation as stand-alone field on the token account and update at intialization and pausing
-    // This new paradigm should separate the decay state and keep the parameters last_balance and current_time independent of the equation
+    check_authority(sender, &accounts.authority.key(), amount)?;
 
-    // transfer, minus from_balance eval, add to_balance eval...update snapshot
-    sender.last_balance_snapshot = from_balance.checked_sub(amount)
-        .ok_or(TokenError::Overflow)?;
+    // Exempt accounts, such as AMM reserves, keep their snapshot
+    let extension = &accounts.chrono_extension;
+    let registry = accounts.exemption_registry.as_deref();
+    let exempt = (
+        is_exempt(extension, registry, sender, accounts.from_owner.as_deref())?,
+        is_exempt(extension, registry, receiver, accounts.to_owner.as_deref())?,
+    );
 
-    receiver.last_balance_snapshot = to_balance.checked_add(amount)
-        .ok_or(TokenError::Overflow)?;
+    let from_snapshot = sender.last_balance_snapshot;
+    let to_snapshot = receiver.last_balance_snapshot;
 
+    // transfer, minus from_balance eval, add to_balance eval...update snapshot
+    // Each side's realized decay goes to its own pool, so snapshot + pool is conserved
+    let (from_balance, to_balance) = settle_transfer(
+        sender,
+        sender_decay_account,
+        receiver,
+        receiver_decay_account,
+        amount,
+        exempt,
+        &config,
+        current_time
+    )?;
 
-    // Update decay pool
-    let new_sender_decayed_amount = sender.last_balance_snapshot - from_balance;
-    let new_receiver_decayed_amount = receiver.last_balance_snapshot - to_balance;
-
-    sender_decay_account.amount = sender_decay_account.amount.checked_add(new_sender_decayed_amount)
-        .ok_or(TokenError::Overflow)?;
+    let fee = withhold_fee(
+        &accounts.chrono_extension,
+        amount,
+        receiver,
+        sender_decay_account,
+        accounts.fee_vault.as_deref_mut()
+    )?;
 
-    receiver_decay_account.amount = receiver_decay_account.amount.checked_add(new_receiver_decayed_amount)
-        .ok_or(TokenError::Overflow)?;
+    emit!(TransferEvent {
+        mint: accounts.mint.key(),
+        from: sender.key(),
+        to: receiver.key(),
+        authority: accounts.authority.key(),
+        amount,
+        fee,
+        from_pre_balance: from_balance,
+        from_post_balance: sender.last_balance_snapshot,
+        to_pre_balance: to_balance,
+        to_post_balance: receiver.last_balance_snapshot,
+        from_decay: realized_decay(from_snapshot, from_balance),
+        to_decay: realized_decay(to_snapshot, to_balance),
+    });
 
     Ok(())
 }
 
-fn check_authority(account: &TokenAccount, authority: &Pubkey, amount: u64) -> Result<()> {
+pub(crate) fn check_authority(account: &TokenAccount, authority: &Pubkey, amount: u64) -> Result<()> {
     if account.owner != *authority {
         if account.delegate != *authority {
             return Err(TokenError::InvalidAuthority.into());
@@ -114,4 +171,36 @@ fn check_authority(account: &TokenAccount, authority: &Pubkey, amount: u64) -> R
         }
     }
     Ok(())
+}
+
+/// Withholds the mint's transfer fee on `amount` from what a settled receiver was credited,
+/// the sender stays debited the full amount. Returns the fee.
+pub(crate) fn withhold_fee(
+    extension: &ChronoExtension,
+    amount: u64,
+    receiver: &mut TokenAccount,
+    sender_decay_pool: &mut DecayPool,
+    fee_vault: Option<&mut FeeVault>,
+) -> Result<u64> {
+    let Some(transfer_fee) = extension.transfer_fee else {
+        return Ok(0);
+    };
+    let fee = transfer_fee.fee_for(amount);
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    receiver.last_balance_snapshot -= fee;
+    match transfer_fee.destination {
+        FeeDestination::SenderDecayPool => {
+            sender_decay_pool.amount = sender_decay_pool.amount.checked_add(fee)
+                .ok_or(TokenError::Overflow)?;
+        }
+        FeeDestination::FeeVault => {
+            let fee_vault = fee_vault.ok_or(TokenError::MissingFeeVault)?;
+            fee_vault.withheld = fee_vault.withheld.checked_add(fee)
+                .ok_or(TokenError::Overflow)?;
+        }
+    }
+    Ok(fee)
 }
\ No newline at end of file
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use super::transfer::{process_transfer, Transfer};
+
+/// `transfer` that also checks the caller's view of the mint's decimals
+pub fn handler(ctx: Context<Transfer>, amount: u64, decimals: u8) -> Result<()> {
+    if decimals != ctx.accounts.mint.decimals {
+        return Err(TokenError::MintDecimalsMismatch.into());
+    }
+
+    process_transfer(ctx.accounts, amount)
+}
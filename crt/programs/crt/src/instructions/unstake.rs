@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use crate::events::StakeEvent;
+use super::stake::{settle_staker, settle_vault, Stake};
+
+/// Returns staked tokens to the account, where they start decaying again
+pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    ctx.accounts.stake_pool.release(current_time)?;
+    let stake_position = &mut ctx.accounts.stake_position;
+
+    if amount == 0 {
+        return Err(TokenError::InvalidAmount.into());
+    }
+    if stake_position.amount < amount {
+        return Err(TokenError::InsufficientStake.into());
+    }
+    stake_position.accrue(&ctx.accounts.stake_pool)?;
+
+    let new_balance = settle_staker(ctx.accounts, amount, 0, current_time)?;
+    settle_vault(ctx.accounts, 0, amount, current_time)?;
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    stake_position.amount -= amount;
+    stake_position.reward_debt = stake_pool.rewards_for(stake_position.amount)?;
+    stake_pool.total_staked -= amount;
+
+    emit!(StakeEvent {
+        stake_pool: stake_pool.key(),
+        token_account: ctx.accounts.token_account.key(),
+        amount,
+        staked: stake_position.amount,
+        total_staked: stake_pool.total_staked,
+        new_balance,
+        unstaked: true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::state::TokenAccount;
+use crate::error::TokenError;
+
+/// Stops merchant collection, the paid period stays valid until it expires
+#[derive(Accounts)]
+pub struct Unsubscribe<'info> {
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<Unsubscribe>) -> Result<()> {
+    let token_account = &mut ctx.accounts.token_account;
+
+    if token_account.owner != ctx.accounts.authority.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    token_account.subscription_auto_renew = false;
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, Stream};
+use crate::error::TokenError;
+use crate::events::StreamWithdrawnEvent;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, settle, CurveConfig};
+
+/// Settles what has streamed so far into the recipient's snapshot. The streamed amount already
+/// counts towards the recipient's balance, decay on it starts once it is settled, by this or
+/// any other settlement. Permissionless, so payroll can be cranked.
+#[derive(Accounts)]
+pub struct WithdrawStream<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"stream", mint.key().as_ref(), stream.sender.as_ref(), &stream.stream_id.to_le_bytes()],
+        bump = stream.bump,
+        has_one = recipient_token_account @ TokenError::InvalidStream
+    )]
+    pub stream: Account<'info, Stream>,
+    #[account(mut)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", recipient_token_account.key().as_ref()],
+        bump = recipient_decay_pool.bump
+    )]
+    pub recipient_decay_pool: Account<'info, DecayPool>,
+    /// CHECK: Owner of `recipient_token_account`, only read for owner program exemptions
+    #[account(address = recipient_token_account.owner)]
+    pub recipient_owner: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<WithdrawStream>) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+    let stream = &mut ctx.accounts.stream;
+
+    if ctx.accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if ctx.accounts.recipient_token_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    let amount = stream.withdrawable(current_time);
+    if amount == 0 {
+        return Err(TokenError::NothingToWithdraw.into());
+    }
+    stream.withdrawn += amount;
+
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        &ctx.accounts.recipient_token_account,
+        ctx.accounts.recipient_owner.as_deref()
+    )?;
+    let new_balance = settle_streamed(
+        &mut ctx.accounts.recipient_token_account,
+        &mut ctx.accounts.recipient_decay_pool,
+        exempt,
+        &config,
+        current_time
+    )?;
+
+    emit!(StreamWithdrawnEvent {
+        stream: stream.key(),
+        recipient_token_account: ctx.accounts.recipient_token_account.key(),
+        amount,
+        withdrawn: stream.withdrawn,
+        new_balance,
+    });
+
+    Ok(())
+}
+
+/// Settles an account, realizing what its streams moved since its last settlement into
+/// its snapshot. Returns the settled balance.
+pub(crate) fn settle_streamed(
+    token_account: &mut TokenAccount,
+    decay_pool: &mut DecayPool,
+    exempt: bool,
+    config: &CurveConfig,
+    current_time: i64,
+) -> Result<u64> {
+    let balance = evaluate_balance_unless_exempt(token_account, exempt, config, current_time)?;
+    settle(token_account, decay_pool, balance, balance, config, current_time)?;
+    Ok(balance)
+}
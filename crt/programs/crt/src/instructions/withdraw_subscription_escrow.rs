@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, SubscriptionPlan, EquationSchedule, TierTable, ExemptionRegistry, FeeVault};
+use crate::error::TokenError;
+use crate::utils::{is_exempt, settle_transfer, CurveConfig};
+use crate::extensions::ChronoExtension;
+use super::transfer::withhold_fee;
+
+#[derive(Accounts)]
+pub struct WithdrawSubscriptionEscrow<'info> {
+    #[account(
+        seeds = [b"subscription_plan", plan.mint.as_ref(), merchant.key().as_ref(), &plan.plan_id.to_le_bytes()],
+        bump = plan.bump,
+        has_one = merchant @ TokenError::SubscriptionPlanMismatch,
+        has_one = merchant_token_account @ TokenError::SubscriptionPlanMismatch
+    )]
+    pub plan: Account<'info, SubscriptionPlan>,
+    #[account(address = plan.mint)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", plan.mint.as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", plan.mint.as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", plan.mint.as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", plan.mint.as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"subscription_escrow", plan.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", escrow.key().as_ref()],
+        bump = escrow_decay_pool.bump
+    )]
+    pub escrow_decay_pool: Account<'info, DecayPool>,
+    #[account(mut)]
+    pub merchant_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", merchant_token_account.key().as_ref()],
+        bump = merchant_decay_pool.bump
+    )]
+    pub merchant_decay_pool: Account<'info, DecayPool>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault", plan.mint.as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+    pub merchant: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawSubscriptionEscrow>, amount: u64) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    if ctx.accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if ctx.accounts.merchant_token_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // The escrow is owned by the plan, the merchant token account by the merchant
+    let extension = &ctx.accounts.chrono_extension;
+    let registry = ctx.accounts.exemption_registry.as_deref();
+    let exempt = (
+        is_exempt(extension, registry, &ctx.accounts.escrow, Some(&ctx.accounts.plan.to_account_info()))?,
+        is_exempt(
+            extension,
+            registry,
+            &ctx.accounts.merchant_token_account,
+            Some(&ctx.accounts.merchant.to_account_info())
+        )?,
+    );
+
+    settle_transfer(
+        &mut ctx.accounts.escrow,
+        &mut ctx.accounts.escrow_decay_pool,
+        &mut ctx.accounts.merchant_token_account,
+        &mut ctx.accounts.merchant_decay_pool,
+        amount,
+        exempt,
+        &config,
+        current_time
+    )?;
+    withhold_fee(
+        extension,
+        amount,
+        &mut ctx.accounts.merchant_token_account,
+        &mut ctx.accounts.escrow_decay_pool,
+        ctx.accounts.fee_vault.as_deref_mut()
+    )?;
+
+    Ok(())
+}
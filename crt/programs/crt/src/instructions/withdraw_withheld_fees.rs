@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::{Mint, TokenAccount, DecayPool, EquationSchedule, TierTable, ExemptionRegistry, FeeVault};
+use crate::error::TokenError;
+use crate::extensions::ChronoExtension;
+use crate::utils::{evaluate_balance_unless_exempt, is_exempt, settle, CurveConfig};
+
+/// Moves withheld transfer fees from the mint's fee vault into a token account
+#[derive(Accounts)]
+pub struct WithdrawWithheldFees<'info> {
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"chrono_extension", mint.key().as_ref()],
+        bump = chrono_extension.bump
+    )]
+    pub chrono_extension: Account<'info, ChronoExtension>,
+    #[account(
+        seeds = [b"equation_schedule", mint.key().as_ref()],
+        bump = schedule.bump
+    )]
+    pub schedule: Option<Account<'info, EquationSchedule>>,
+    #[account(
+        seeds = [b"tier_table", mint.key().as_ref()],
+        bump = tier_table.bump
+    )]
+    pub tier_table: Option<Account<'info, TierTable>>,
+    #[account(
+        seeds = [b"exemption_registry", mint.key().as_ref()],
+        bump = exemption_registry.bump
+    )]
+    pub exemption_registry: Option<Account<'info, ExemptionRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"fee_vault", mint.key().as_ref()],
+        bump = fee_vault.bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"decay_pool", destination.key().as_ref()],
+        bump = destination_decay_pool.bump
+    )]
+    pub destination_decay_pool: Account<'info, DecayPool>,
+    /// CHECK: Owner of `destination`, only read for owner program exemptions
+    #[account(address = destination.owner)]
+    pub destination_owner: Option<UncheckedAccount<'info>>,
+    pub fee_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawWithheldFees>, amount: u64) -> Result<()> {
+    let current_time = ctx.accounts.chrono_extension.time_base.now(&Clock::get()?);
+    let config = CurveConfig::load(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.schedule.as_deref(),
+        ctx.accounts.tier_table.as_deref()
+    )?;
+
+    let transfer_fee = ctx.accounts.chrono_extension.transfer_fee
+        .ok_or(TokenError::TransferFeeNotEnabled)?;
+    if transfer_fee.fee_authority != ctx.accounts.fee_authority.key() {
+        return Err(TokenError::InvalidFeeAuthority.into());
+    }
+
+    let destination = &mut ctx.accounts.destination;
+    if destination.mint != ctx.accounts.mint.key() {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if ctx.accounts.mint.paused {
+        return Err(TokenError::MintPaused.into());
+    }
+    if destination.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    fee_vault.withheld = fee_vault.withheld.checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+
+    let exempt = is_exempt(
+        &ctx.accounts.chrono_extension,
+        ctx.accounts.exemption_registry.as_deref(),
+        destination,
+        ctx.accounts.destination_owner.as_deref()
+    )?;
+    let balance = evaluate_balance_unless_exempt(destination, exempt, &config, current_time)?;
+    let new_balance = balance.checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    settle(destination, &mut ctx.accounts.destination_decay_pool, balance, new_balance, &config, current_time)?;
+
+    Ok(())
+}
@@ -9,7 +9,7 @@ mod extensions;
 mod tokenizer;
 
 use instructions::*;
-use crate::state::{ChronoEquationType, PauseType, EquationParams};
+use crate::state::{ChronoEquationType, PauseType, EquationParams, ReUpPolicy, RefundPolicy, SchedulePhase, TimeBase, Tier, ExemptionKind, TransferFeeConfig, EmissionSchedule, EscrowDecayMode};
 
 declare_id!("crnXvAtgkLMzJKEFdveTZ4Redy3mHa1YY9UXP9wZ91c");
 
@@ -17,29 +17,40 @@ declare_id!("crnXvAtgkLMzJKEFdveTZ4Redy3mHa1YY9UXP9wZ91c");
 pub mod chrono_token {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_mint(ctx: Context<InitializeMint>,
                            decimals: u8,
                            supply: u64,
                            freeze_authority: Option<Pubkey>,
-                           _bump: u8,
                            enable_chrono_hook: bool,
                            chrono_hook_program_id: Option<Pubkey>,
                            equation_type: Option<ChronoEquationType>,
                            pause_type: Option<PauseType>,
                            equation_params: Option<EquationParams>,
                            reup_percentage: Option<u8>,
+                           reup_policy: Option<ReUpPolicy>,
+                           time_base: Option<TimeBase>,
+                           transfer_fee: Option<TransferFeeConfig>,
+                           max_supply: Option<u64>,
+                           emission: Option<EmissionSchedule>,
+                           pause_authority: Option<Pubkey>,
     ) -> Result<()> {
         instructions::initialize_mint::handler(ctx,
                                                decimals,
                                                supply,
                                                freeze_authority,
-                                               _bump,
                                                enable_chrono_hook,
                                                chrono_hook_program_id,
                                                equation_type,
                                                pause_type,
                                                equation_params,
-                                               reup_percentage
+                                               reup_percentage,
+                                               reup_policy,
+                                               time_base,
+                                               transfer_fee,
+                                               max_supply,
+                                               emission,
+                                               pause_authority,
         )
     }
 
@@ -55,6 +66,17 @@ pub mod chrono_token {
         instructions::transfer::handler(ctx, amount)
     }
 
+    pub fn transfer_checked(ctx: Context<Transfer>, amount: u64, decimals: u8) -> Result<()> {
+        instructions::transfer_checked::handler(ctx, amount, decimals)
+    }
+
+    pub fn batch_transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchTransfer<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::batch_transfer::handler(ctx, amounts)
+    }
+
     pub fn mint_to(ctx: Context<MintTo>, amount: u64) -> Result<()> {
         instructions::mint_to::handler(ctx, amount)
     }
@@ -70,4 +92,216 @@ pub mod chrono_token {
     pub fn burn(ctx: Context<Burn>, amount: u64) -> Result<()> {
         instructions::burn::handler(ctx, amount)
     }
+
+    pub fn migrate_decay_pool(ctx: Context<MigrateDecayPool>) -> Result<()> {
+        instructions::migrate_decay_pool::handler(ctx)
+    }
+
+    pub fn migrate_chrono_extension(ctx: Context<MigrateChronoExtension>) -> Result<()> {
+        instructions::migrate_chrono_extension::handler(ctx)
+    }
+
+    pub fn create_subscription_plan(ctx: Context<CreateSubscriptionPlan>,
+                                    plan_id: u64,
+                                    price: u64,
+                                    period: i64,
+                                    grace_period: i64,
+                                    max_renewals: u32,
+                                    refund_policy: RefundPolicy,
+    ) -> Result<()> {
+        instructions::create_subscription_plan::handler(ctx,
+                                                        plan_id,
+                                                        price,
+                                                        period,
+                                                        grace_period,
+                                                        max_renewals,
+                                                        refund_policy,
+        )
+    }
+
+    pub fn renew_subscription(ctx: Context<RenewSubscription>) -> Result<()> {
+        instructions::renew_subscription::handler(ctx)
+    }
+
+    pub fn subscribe(ctx: Context<Subscribe>) -> Result<()> {
+        instructions::subscribe::handler(ctx)
+    }
+
+    pub fn unsubscribe(ctx: Context<Unsubscribe>) -> Result<()> {
+        instructions::unsubscribe::handler(ctx)
+    }
+
+    pub fn collect_subscription(ctx: Context<CollectSubscription>) -> Result<()> {
+        instructions::collect_subscription::handler(ctx)
+    }
+
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        instructions::cancel_subscription::handler(ctx)
+    }
+
+    pub fn withdraw_subscription_escrow(ctx: Context<WithdrawSubscriptionEscrow>, amount: u64) -> Result<()> {
+        instructions::withdraw_subscription_escrow::handler(ctx, amount)
+    }
+
+    pub fn get_balance(ctx: Context<GetBalance>) -> Result<BalanceView> {
+        instructions::get_balance::handler(ctx)
+    }
+
+    pub fn initialize_schedule(ctx: Context<InitializeSchedule>, phases: Vec<SchedulePhase>) -> Result<()> {
+        instructions::initialize_schedule::handler(ctx, phases)
+    }
+
+    pub fn initialize_tier_table(ctx: Context<InitializeTierTable>, tiers: Vec<Tier>) -> Result<()> {
+        instructions::initialize_tier_table::handler(ctx, tiers)
+    }
+
+    pub fn set_account_equation(ctx: Context<SetAccountEquation>, tier: Option<u8>) -> Result<()> {
+        instructions::set_account_equation::handler(ctx, tier)
+    }
+
+    pub fn initialize_exemption_registry(ctx: Context<InitializeExemptionRegistry>) -> Result<()> {
+        instructions::initialize_exemption_registry::handler(ctx)
+    }
+
+    pub fn add_exemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddExemption<'info>>,
+        kind: ExemptionKind,
+        key: Pubkey,
+    ) -> Result<()> {
+        instructions::add_exemption::handler(ctx, kind, key)
+    }
+
+    pub fn remove_exemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveExemption<'info>>,
+        kind: ExemptionKind,
+        key: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_exemption::handler(ctx, kind, key)
+    }
+
+    pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+        instructions::initialize_fee_vault::handler(ctx)
+    }
+
+    pub fn withdraw_withheld_fees(ctx: Context<WithdrawWithheldFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_withheld_fees::handler(ctx, amount)
+    }
+
+    pub fn emit_scheduled(ctx: Context<EmitScheduled>) -> Result<()> {
+        instructions::emit_scheduled::handler(ctx)
+    }
+
+    pub fn pause_mint(ctx: Context<PauseMint>, stop_decay: bool) -> Result<()> {
+        instructions::pause_mint::handler(ctx, stop_decay)
+    }
+
+    pub fn resume_mint(ctx: Context<ResumeMint>) -> Result<()> {
+        instructions::resume_mint::handler(ctx)
+    }
+
+    pub fn create_distributor(
+        ctx: Context<CreateDistributor>,
+        distributor_id: u64,
+        merkle_root: [u8; 32],
+        total: u64,
+        claim_deadline: i64,
+    ) -> Result<()> {
+        instructions::create_distributor::handler(ctx, distributor_id, merkle_root, total, claim_deadline)
+    }
+
+    pub fn claim(ctx: Context<Claim>, index: u64, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim::handler(ctx, index, amount, proof)
+    }
+
+    pub fn clawback_distributor(ctx: Context<ClawbackDistributor>) -> Result<()> {
+        instructions::clawback_distributor::handler(ctx)
+    }
+
+    pub fn open_snapshot(
+        ctx: Context<OpenSnapshot>,
+        snapshot_id: u64,
+        snapshot_time: i64,
+        record_deadline: i64,
+    ) -> Result<()> {
+        instructions::open_snapshot::handler(ctx, snapshot_id, snapshot_time, record_deadline)
+    }
+
+    pub fn record_snapshot_balance(ctx: Context<RecordSnapshotBalance>) -> Result<()> {
+        instructions::record_snapshot_balance::handler(ctx)
+    }
+
+    pub fn get_snapshot_balance(ctx: Context<GetSnapshotBalance>) -> Result<SnapshotBalanceView> {
+        instructions::get_snapshot_balance::handler(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        escrow_id: u64,
+        amount: u64,
+        beneficiary: Pubkey,
+        unlock_time: i64,
+        arbiter: Option<Pubkey>,
+        decay_mode: EscrowDecayMode,
+    ) -> Result<()> {
+        instructions::create_escrow::handler(ctx, escrow_id, amount, beneficiary, unlock_time, arbiter, decay_mode)
+    }
+
+    pub fn release_escrow(ctx: Context<ReleaseEscrow>) -> Result<()> {
+        instructions::release_escrow::handler(ctx)
+    }
+
+    pub fn refund_escrow(ctx: Context<ReleaseEscrow>) -> Result<()> {
+        instructions::refund_escrow::handler(ctx)
+    }
+
+    pub fn initialize_stake_pool(ctx: Context<InitializeStakePool>, reward_duration: i64) -> Result<()> {
+        instructions::initialize_stake_pool::handler(ctx, reward_duration)
+    }
+
+    pub fn open_stake_position(ctx: Context<OpenStakePosition>) -> Result<()> {
+        instructions::open_stake_position::handler(ctx)
+    }
+
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        instructions::stake::handler(ctx, amount)
+    }
+
+    pub fn unstake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        instructions::unstake::handler(ctx, amount)
+    }
+
+    pub fn claim_stake_rewards(ctx: Context<Stake>) -> Result<()> {
+        instructions::claim_stake_rewards::handler(ctx)
+    }
+
+    pub fn harvest_decay(ctx: Context<HarvestDecay>) -> Result<()> {
+        instructions::harvest_decay::handler(ctx)
+    }
+
+    pub fn create_stream(
+        ctx: Context<CreateStream>,
+        stream_id: u64,
+        rate_per_unit: u64,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<()> {
+        instructions::create_stream::handler(ctx, stream_id, rate_per_unit, start_time, end_time)
+    }
+
+    pub fn withdraw_stream(ctx: Context<WithdrawStream>) -> Result<()> {
+        instructions::withdraw_stream::handler(ctx)
+    }
+
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        instructions::cancel_stream::handler(ctx)
+    }
+
+    pub fn add_harvest_source(ctx: Context<SetHarvestSource>) -> Result<()> {
+        instructions::add_harvest_source::handler(ctx)
+    }
+
+    pub fn remove_harvest_source(ctx: Context<SetHarvestSource>) -> Result<()> {
+        instructions::remove_harvest_source::handler(ctx)
+    }
 }
\ No newline at end of file
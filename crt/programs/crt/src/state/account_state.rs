@@ -7,4 +7,6 @@ pub enum AccountState {
     Pause,
     Unpause,
     Frozen,
+    /// Program-owned vault, never decays
+    Vault,
 }
\ No newline at end of file
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+/// Merkle airdrop of a mint. `total` is issued when the distributor is created and
+/// credited to claimers as they claim, so nothing decays before it is claimed.
+#[account]
+pub struct Distributor {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub distributor_id: u64,
+    /// Root over `keccak(index || claimant || amount)` leaves, pairs hashed in sorted order
+    pub merkle_root: [u8; 32],
+    pub total: u64,
+    pub claimed: u64,
+    /// Claims close at this time, after which the authority can claw back the rest
+    pub claim_deadline: i64,
+    pub bump: u8,
+}
+
+impl Distributor {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 8 + 8 + 8 + 1;
+
+    pub fn verify(&self, index: u64, claimant: &Pubkey, amount: u64, proof: &[[u8; 32]]) -> bool {
+        let leaf = keccak::hashv(&[&index.to_le_bytes(), claimant.as_ref(), &amount.to_le_bytes()]).0;
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            if node <= *sibling {
+                keccak::hashv(&[&node, sibling]).0
+            } else {
+                keccak::hashv(&[sibling, &node]).0
+            }
+        });
+        root == self.merkle_root
+    }
+
+    pub fn unclaimed(&self) -> u64 {
+        self.total.saturating_sub(self.claimed)
+    }
+}
+
+/// Marks one leaf of a distributor as claimed
+#[account]
+pub struct ClaimStatus {
+    pub distributor: Pubkey,
+    pub index: u64,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    pub claimed_at: i64,
+}
+
+impl ClaimStatus {
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 8 + 8;
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum EmissionCurve {
+    /// The same allowance every epoch
+    Constant { amount_per_epoch: u64 },
+    /// The per-epoch allowance halves every `halving_interval` epochs
+    Halving { initial_per_epoch: u64, halving_interval: u64 },
+}
+
+/// Issuance allowance of a mint. Allowance not minted in an epoch carries over.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct EmissionSchedule {
+    /// Start of the first epoch, in the mint's time base
+    pub start: i64,
+    /// Epoch length, in the mint's time base
+    pub epoch_length: i64,
+    pub curve: EmissionCurve,
+    /// Token account `emit_scheduled` mints into
+    pub treasury: Pubkey,
+}
+
+impl EmissionSchedule {
+    pub fn validate(&self) -> Result<()> {
+        let valid = self.epoch_length > 0 && match self.curve {
+            EmissionCurve::Constant { amount_per_epoch } => amount_per_epoch > 0,
+            EmissionCurve::Halving { initial_per_epoch, halving_interval } =>
+                initial_per_epoch > 0 && halving_interval > 0,
+        };
+        if !valid {
+            return Err(TokenError::InvalidEmissionSchedule.into());
+        }
+        Ok(())
+    }
+
+    /// Total allowance unlocked up to `current_time`, the running epoch included
+    pub fn unlocked(&self, current_time: i64) -> u64 {
+        if current_time < self.start {
+            return 0;
+        }
+        let epochs = ((current_time - self.start) / self.epoch_length) as u64 + 1;
+
+        match self.curve {
+            EmissionCurve::Constant { amount_per_epoch } => amount_per_epoch.saturating_mul(epochs),
+            EmissionCurve::Halving { initial_per_epoch, halving_interval } => {
+                let mut unlocked = 0u64;
+                let mut remaining = epochs;
+                let mut per_epoch = initial_per_epoch;
+                while remaining > 0 && per_epoch > 0 {
+                    let period = remaining.min(halving_interval);
+                    unlocked = unlocked.saturating_add(per_epoch.saturating_mul(period));
+                    remaining -= period;
+                    per_epoch >>= 1;
+                }
+                unlocked
+            }
+        }
+    }
+}
@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use crate::state::{TimeBase, BASIS_POINTS};
+use crate::utils::{evaluate_curve, DECIMALS_FACTOR};
+
+/// How far ahead curves are checked to keep producing balances, in seconds
+const VALIDATION_HORIZON: i64 = 100 * 365 * 86_400;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
 pub enum ChronoEquationType {
@@ -7,6 +13,9 @@ pub enum ChronoEquationType {
     Deflationary,
     Linear,
     Exponential,
+    Vesting,
+    /// S-curve decay, slow at first, steepest at `midpoint`, then flattening towards zero
+    Logistic,
 }
 
 impl ChronoEquationType {
@@ -26,7 +35,7 @@ impl ChronoEquationType {
                 let snapshot_time = params.snapshot_time.ok_or(ProgramError::InvalidAccountData)?;
                 let decay_rate = params.decay_rate.ok_or(ProgramError::InvalidAccountData)?;
                 let time_unit = params.time_unit.ok_or(ProgramError::InvalidAccountData)?;
-                format!("max(0, {} - (({}) - {}) * {} / {})", x, t, snapshot_time, decay_rate, time_unit)
+                format!("{} - (({}) - {}) * {} / {}", x, t, snapshot_time, decay_rate, time_unit)
             }
             ChronoEquationType::Linear => {
                 let snapshot_time = params.snapshot_time.ok_or(ProgramError::InvalidAccountData)?;
@@ -39,6 +48,19 @@ impl ChronoEquationType {
                 let time_unit = params.time_unit.ok_or(ProgramError::InvalidAccountData)?;
                 format!("{} * exp(-{} * (({}) - {}) / {})", x, decay_constant, t, snapshot_time, time_unit)
             }
+            ChronoEquationType::Vesting => {
+                format!("{}", x)
+            }
+            ChronoEquationType::Logistic => {
+                let snapshot_time = params.snapshot_time.ok_or(ProgramError::InvalidAccountData)?;
+                let midpoint = params.midpoint.ok_or(ProgramError::InvalidAccountData)?;
+                let steepness = params.steepness.ok_or(ProgramError::InvalidAccountData)?;
+                let time_unit = params.time_unit.ok_or(ProgramError::InvalidAccountData)?;
+                format!(
+                    "{} * (1 + exp(0 - {} * {} / {})) / (1 + exp({} * ((({}) - {}) - {}) / {}))",
+                    x, steepness, midpoint, time_unit, steepness, t, snapshot_time, midpoint, time_unit
+                )
+            }
             // ChronoEquationType::Paused => {
             //     "x".to_string()
             // }
@@ -77,20 +99,117 @@ impl ChronoEquationType {
                 time_unit: Some(86400), // Assuming daily decay
                 ..Default::default()
             },
+            ChronoEquationType::Vesting => EquationParams::default(),
+            ChronoEquationType::Logistic => EquationParams {
+                midpoint: Some(0), // Set this appropriately
+                steepness: Some(1.0), // Set this appropriately
+                time_unit: Some(86400), // Assuming daily steepness
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Checks that `params` carries everything this curve reads, with usable values
+    pub fn validate_params(&self, params: &EquationParams, time_base: TimeBase) -> Result<()> {
+        match self {
+            // Expiry is tracked per account by its subscription plan
+            ChronoEquationType::Subscription => {}
+            ChronoEquationType::Inflationary => {
+                require_param(params.inflation_rate, "inflation_rate", self)?;
+                require_time_unit(params, self)?;
+            }
+            ChronoEquationType::Deflationary => {
+                require_param(params.decay_rate, "decay_rate", self)?;
+                require_time_unit(params, self)?;
+            }
+            ChronoEquationType::Linear => {
+                require_param(params.slope, "slope", self)?;
+            }
+            ChronoEquationType::Exponential => {
+                let decay_constant = require_param(params.decay_constant, "decay_constant", self)?;
+                if !decay_constant.is_finite() || decay_constant < 0.0 {
+                    msg!("{:?} decay_constant must be finite and non-negative", self);
+                    return Err(TokenError::InvalidEquationParams.into());
+                }
+                require_time_unit(params, self)?;
+                check_horizon(self, params, time_base)?;
+            }
+            ChronoEquationType::Vesting => {
+                let vesting_start = require_param(params.vesting_start, "vesting_start", self)?;
+                let cliff_time = require_param(params.cliff_time, "cliff_time", self)?;
+                let vesting_duration = require_param(params.vesting_duration, "vesting_duration", self)?;
+                if vesting_duration <= 0 || cliff_time < vesting_start {
+                    msg!("{:?} needs a positive vesting_duration and a cliff_time after vesting_start", self);
+                    return Err(TokenError::InvalidEquationParams.into());
+                }
+            }
+            ChronoEquationType::Logistic => {
+                let midpoint = require_param(params.midpoint, "midpoint", self)?;
+                let steepness = require_param(params.steepness, "steepness", self)?;
+                if midpoint < 0 || !steepness.is_finite() || steepness <= 0.0 {
+                    msg!("{:?} needs a non-negative midpoint and a finite, positive steepness", self);
+                    return Err(TokenError::InvalidEquationParams.into());
+                }
+                require_time_unit(params, self)?;
+                check_horizon(self, params, time_base)?;
+            }
         }
+
+        // Bounds apply to every curve
+        if params.min_balance_ratio.is_some_and(|ratio| ratio > BASIS_POINTS) {
+            msg!("min_balance_ratio must be at most {} basis points", BASIS_POINTS);
+            return Err(TokenError::InvalidEquationParams.into());
+        }
+        Ok(())
     }
 
     pub fn get_equation_string(&self) -> String {
         match self {
             ChronoEquationType::Subscription => "x * (t <= expiration_time ? 1 : 0)".to_string(),
             ChronoEquationType::Inflationary => "x + (t * inflation_rate / time_unit)".to_string(),
-            ChronoEquationType::Deflationary => "max(0, x - (t * decay_rate / time_unit))".to_string(),
+            // Clamped at zero when evaluated, the tokenizer has no `max`
+            ChronoEquationType::Deflationary => "x - (t * decay_rate / time_unit)".to_string(),
             ChronoEquationType::Linear => "x + (t * slope)".to_string(),
-            ChronoEquationType::Exponential => "x * exp(-decay_constant * t / time_unit)".to_string(),
+            ChronoEquationType::Exponential => "x * exp(0 - decay_constant * t / time_unit)".to_string(),
+            // Vesting never changes the balance, it only locks part of it
+            ChronoEquationType::Vesting => "x".to_string(),
+            // Runs along the account's curve age, so settling often does not restart the slow early phase
+            ChronoEquationType::Logistic =>
+                "x * (1 + exp(steepness * (age - midpoint) / time_unit)) / (1 + exp(steepness * (age + t - midpoint) / time_unit))".to_string(),
         }
     }
 }
 
+fn require_param<T>(value: Option<T>, name: &str, equation_type: &ChronoEquationType) -> Result<T> {
+    value.ok_or_else(|| {
+        msg!("{:?} equation requires {}", equation_type, name);
+        TokenError::MissingEquationParams.into()
+    })
+}
+
+/// Samples the curve from one token over the next 100 years in the mint's time base, down to
+/// its smallest steps and at the midpoint, so a mint cannot be created with a curve that stops
+/// evaluating later on
+fn check_horizon(equation_type: &ChronoEquationType, params: &EquationParams, time_base: TimeBase) -> Result<()> {
+    let horizon = time_base.duration_from_seconds(VALIDATION_HORIZON);
+    let mut samples: Vec<i64> = (0..16).map(|step| horizon >> (2 * step)).collect();
+    samples.extend(params.midpoint);
+    for t in samples {
+        evaluate_curve(DECIMALS_FACTOR, equation_type, params, 0, 0, t).inspect_err(|_| {
+            msg!("{:?} params do not evaluate at t = {}", equation_type, t);
+        })?;
+    }
+    Ok(())
+}
+
+fn require_time_unit(params: &EquationParams, equation_type: &ChronoEquationType) -> Result<()> {
+    if require_param(params.time_unit, "time_unit", equation_type)? == 0 {
+        msg!("{:?} time_unit must be greater than zero", equation_type);
+        return Err(TokenError::InvalidEquationParams.into());
+    }
+    Ok(())
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Default)]
 pub struct EquationParams {
     pub snapshot_time: Option<i64>,
@@ -101,9 +220,25 @@ pub struct EquationParams {
     pub slope: Option<i64>,
     pub decay_constant: Option<f64>,
     pub reup_boost: Option<u64>,
+    pub cliff_time: Option<i64>,
+    pub vesting_start: Option<i64>,
+    pub vesting_duration: Option<i64>,
+    /// Exponential decay given as a wall-clock half-life, converted into `decay_constant` in the mint's time base
+    pub half_life_seconds: Option<u64>,
+    /// Curve age at which a logistic curve decays fastest
+    pub midpoint: Option<i64>,
+    /// Logistic steepness per `time_unit`
+    pub steepness: Option<f64>,
+    /// Share of the settled snapshot an evaluation always keeps, in basis points
+    pub min_balance_ratio: Option<u16>,
+    /// Hard cap a curve can grow a balance to
+    pub max_balance: Option<u64>,
 }
 
 impl EquationParams {
+    /// Serialized size, every field is an `Option` of an 8 byte value except the ratio
+    pub const LEN: usize = 15 * (1 + 8) + (1 + 2);
+
     pub fn new() -> Self {
         Self {
             snapshot_time: None,
@@ -114,6 +249,66 @@ impl EquationParams {
             slope: None,
             decay_constant: None,
             reup_boost: None,
+            cliff_time: None,
+            vesting_start: None,
+            vesting_duration: None,
+            half_life_seconds: None,
+            midpoint: None,
+            steepness: None,
+            min_balance_ratio: None,
+            max_balance: None,
+        }
+    }
+
+    /// Converts `half_life_seconds` into the `decay_constant` the Exponential curve reads.
+    /// The half-life is wall-clock seconds, measured in the mint's time base from here on,
+    /// and `time_unit` defaults to the half-life itself.
+    pub fn apply_half_life(&mut self, time_base: TimeBase) -> Result<()> {
+        let Some(half_life_seconds) = self.half_life_seconds else {
+            return Ok(());
+        };
+        let half_life = time_base.duration_from_seconds(half_life_seconds.min(i64::MAX as u64) as i64);
+        if half_life <= 0 || self.decay_constant.is_some() {
+            msg!("half_life_seconds must be at least one {:?} step and cannot be combined with decay_constant", time_base);
+            return Err(TokenError::InvalidEquationParams.into());
         }
+
+        let time_unit = *self.time_unit.get_or_insert(half_life as u64);
+        self.decay_constant = Some(std::f64::consts::LN_2 * time_unit as f64 / half_life as f64);
+        Ok(())
+    }
+
+    /// Clamps a balance evaluated from `last_balance_snapshot` to the mint's floor and ceiling.
+    /// The floor is a share of the account's `floor_basis` and never lifts a balance above its
+    /// snapshot. The ceiling only stops growth, balances settled above it are not cut back.
+    pub fn bound(&self, floor_basis: u64, last_balance_snapshot: u64, evaluated_balance: u64) -> u64 {
+        let floor = self.min_balance_ratio
+            .map(|ratio| (floor_basis as u128 * ratio as u128 / BASIS_POINTS as u128) as u64)
+            .unwrap_or(0)
+            .min(last_balance_snapshot);
+        let ceiling = self.max_balance
+            .map(|max_balance| max_balance.max(last_balance_snapshot))
+            .unwrap_or(u64::MAX);
+
+        evaluated_balance.min(ceiling).max(floor)
+    }
+
+    /// Part of `balance` that has not vested yet at `current_time`, out of an account's `vesting_total`
+    pub fn locked_amount(&self, vesting_total: u64, balance: u64, current_time: i64) -> u64 {
+        let (Some(vesting_start), Some(cliff_time), Some(vesting_duration)) =
+            (self.vesting_start, self.cliff_time, self.vesting_duration) else {
+            return 0;
+        };
+
+        let vested = if current_time < cliff_time {
+            0
+        } else if vesting_duration <= 0 {
+            vesting_total
+        } else {
+            let elapsed = current_time.saturating_sub(vesting_start).clamp(0, vesting_duration);
+            (vesting_total as u128 * elapsed as u128 / vesting_duration as u128) as u64
+        };
+
+        vesting_total.saturating_sub(vested).min(balance)
     }
 }
\ No newline at end of file
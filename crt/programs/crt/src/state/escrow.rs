@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+/// How an escrow vault's balance evolves while it is locked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum EscrowDecayMode {
+    /// The vault is held flat, as if its decay were paused
+    Exempt,
+    /// The vault decays on the mint's curve, whoever receives it bears the decay
+    /// and the depositor's pool is credited with it
+    Shared,
+}
+
+#[account]
+pub struct Escrow {
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    /// Account the deposit came from, its pool receives a shared vault's decay
+    pub depositor_token_account: Pubkey,
+    pub beneficiary: Pubkey,
+    /// Program-owned token account holding the deposit
+    pub vault: Pubkey,
+    pub escrow_id: u64,
+    /// Amount deposited, before any decay
+    pub amount: u64,
+    /// Time after which the beneficiary can release the vault
+    pub unlock_time: i64,
+    /// May release or refund the vault at any time, settling the escrow's condition
+    pub arbiter: Option<Pubkey>,
+    pub decay_mode: EscrowDecayMode,
+    /// Set once the vault has been released or refunded
+    pub settled: bool,
+    pub bump: u8,
+}
+
+impl Escrow {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + (1 + 32) + 1 + 1 + 1;
+
+    pub fn is_exempt(&self) -> bool {
+        self.decay_mode == EscrowDecayMode::Exempt
+    }
+
+    pub fn is_arbiter(&self, key: &Pubkey) -> bool {
+        self.arbiter.as_ref() == Some(key)
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+pub const MAX_EXEMPTIONS: usize = 16;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ExemptionKind {
+    /// A single token account
+    TokenAccount,
+    /// Every token account whose owner is an account of this program, e.g. an AMM pool
+    OwnerProgram,
+}
+
+/// Mint-level list of accounts whose balances stay flat at their snapshot
+#[account]
+pub struct ExemptionRegistry {
+    pub mint: Pubkey,
+    pub token_accounts: Vec<Pubkey>,
+    pub owner_programs: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl ExemptionRegistry {
+    pub const LEN: usize = 8 + 32 + 2 * (4 + MAX_EXEMPTIONS * 32) + 1;
+
+    pub fn entries_mut(&mut self, kind: ExemptionKind) -> &mut Vec<Pubkey> {
+        match kind {
+            ExemptionKind::TokenAccount => &mut self.token_accounts,
+            ExemptionKind::OwnerProgram => &mut self.owner_programs,
+        }
+    }
+
+    /// Whether `token_account` is exempt. `owner` is the token account's owner, only needed
+    /// once owner programs are registered.
+    pub fn is_exempt(&self, token_account: &Pubkey, owner: Option<&AccountInfo>) -> Result<bool> {
+        if self.token_accounts.contains(token_account) {
+            return Ok(true);
+        }
+        if self.owner_programs.is_empty() {
+            return Ok(false);
+        }
+        let owner = owner.ok_or(TokenError::MissingExemptionOwner)?;
+        Ok(self.owner_programs.contains(owner.owner))
+    }
+}
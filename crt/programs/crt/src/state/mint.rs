@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{ChronoEquationType, PauseType};
+use crate::state::{ChronoEquationType, PauseType, EmissionSchedule};
+use crate::error::TokenError;
 
 #[account]
 pub struct Mint {
@@ -11,8 +12,47 @@ pub struct Mint {
     pub pause_type: PauseType,
     pub supply: u64,
     pub pause_hook: Pubkey,
+    /// Hard cap on `supply`
+    pub max_supply: Option<u64>,
+    pub emission: Option<EmissionSchedule>,
+    /// Total issued under `emission`
+    pub emitted: u64,
+    /// Can halt all token movement on the mint
+    pub pause_authority: Option<Pubkey>,
+    pub paused: bool,
 }
 
 impl Mint {
     pub const LEN: usize = 8 + 32 + 1 + 1 + 32 + 200; // Approximate size, adjust as needed
+
+    /// Allowance `emit_scheduled` can mint at `current_time`
+    pub fn emittable(&self, current_time: i64) -> u64 {
+        let Some(emission) = self.emission else {
+            return 0;
+        };
+        let allowance = emission.unlocked(current_time).saturating_sub(self.emitted);
+        match self.max_supply {
+            Some(max_supply) => allowance.min(max_supply.saturating_sub(self.supply)),
+            None => allowance,
+        }
+    }
+
+    /// Adds `amount` to the supply within the supply cap and the emission allowance
+    pub fn issue(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        let supply = self.supply.checked_add(amount).ok_or(TokenError::Overflow)?;
+        if self.max_supply.is_some_and(|max_supply| supply > max_supply) {
+            return Err(TokenError::MaxSupplyExceeded.into());
+        }
+
+        if let Some(emission) = self.emission {
+            let emitted = self.emitted.checked_add(amount).ok_or(TokenError::Overflow)?;
+            if emitted > emission.unlocked(current_time) {
+                return Err(TokenError::EmissionAllowanceExceeded.into());
+            }
+            self.emitted = emitted;
+        }
+
+        self.supply = supply;
+        Ok(())
+    }
 }
\ No newline at end of file
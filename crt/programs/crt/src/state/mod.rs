@@ -4,6 +4,19 @@ pub mod decay_pool;
 pub mod account_state;
 pub mod pause;
 pub mod equation_type;
+pub mod reup_policy;
+pub mod subscription_plan;
+pub mod schedule;
+pub mod time_base;
+pub mod tier;
+pub mod exemption_registry;
+pub mod transfer_fee;
+pub mod emission;
+pub mod distributor;
+pub mod snapshot;
+pub mod escrow;
+pub mod stake;
+pub mod stream;
 
 pub use mint::*;
 pub use token_account::*;
@@ -11,3 +24,16 @@ pub use decay_pool::*;
 pub use account_state::*;
 pub use pause::*;
 pub use equation_type::*;
+pub use reup_policy::*;
+pub use subscription_plan::*;
+pub use schedule::*;
+pub use time_base::*;
+pub use tier::*;
+pub use exemption_registry::*;
+pub use transfer_fee::*;
+pub use emission::*;
+pub use distributor::*;
+pub use snapshot::*;
+pub use escrow::*;
+pub use stake::*;
+pub use stream::*;
@@ -4,4 +4,22 @@ use anchor_lang::prelude::*;
 pub enum PauseType {
     Pause,
     ReUp,
+}
+
+/// A mint pause that also stopped decay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct DecayPause {
+    pub paused_at: i64,
+    /// Unset while the pause lasts
+    pub resumed_at: Option<i64>,
+}
+
+impl DecayPause {
+    pub const LEN: usize = 8 + (1 + 8);
+
+    /// Part of `[from, to)` that falls inside this pause
+    pub fn overlap(&self, from: i64, to: i64) -> i64 {
+        let resumed_at = self.resumed_at.unwrap_or(i64::MAX);
+        resumed_at.min(to).saturating_sub(self.paused_at.max(from)).max(0)
+    }
 }
\ No newline at end of file
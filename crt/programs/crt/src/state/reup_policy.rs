@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+pub const REUP_SCHEDULE_LEN: usize = 7;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct ReUpPolicy {
+    /// Minimum time between two ReUps of the same account
+    pub min_interval: i64,
+    /// Time after the last ReUp before the streak resets
+    pub max_interval: i64,
+    /// Percentage of the decay pool paid out, indexed by streak length.
+    /// Streaks longer than the schedule keep the last entry.
+    pub percentage_schedule: [u8; REUP_SCHEDULE_LEN],
+}
+
+impl ReUpPolicy {
+    pub fn validate(&self) -> Result<()> {
+        if self.min_interval < 0 || self.max_interval < self.min_interval {
+            return Err(TokenError::InvalidReUpPolicy.into());
+        }
+        if self.percentage_schedule.iter().any(|percentage| *percentage > 100) {
+            return Err(TokenError::InvalidReUpPercentage.into());
+        }
+        // The schedule has to reward longer streaks, never punish them
+        if self.percentage_schedule.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(TokenError::InvalidReUpPolicy.into());
+        }
+        Ok(())
+    }
+
+    /// Streak length after a ReUp at `current_time`
+    pub fn next_streak(&self, last_reup_time: i64, reup_streak: u32, current_time: i64) -> Result<u32> {
+        if reup_streak == 0 {
+            return Ok(1);
+        }
+        let elapsed = current_time.saturating_sub(last_reup_time);
+        if elapsed < self.min_interval {
+            return Err(TokenError::ReUpCooldown.into());
+        }
+        if elapsed > self.max_interval {
+            return Ok(1);
+        }
+        Ok(reup_streak.saturating_add(1))
+    }
+
+    pub fn percentage_for(&self, streak: u32) -> u8 {
+        let index = (streak.saturating_sub(1) as usize).min(REUP_SCHEDULE_LEN - 1);
+        self.percentage_schedule[index]
+    }
+}
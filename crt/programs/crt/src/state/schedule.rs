@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use super::{ChronoEquationType, EquationParams, TimeBase};
+
+pub const MAX_SCHEDULE_PHASES: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SchedulePhase {
+    /// Time after the token account's creation at which this phase starts
+    pub start_offset: i64,
+    pub equation_type: ChronoEquationType,
+    pub params: EquationParams,
+}
+
+impl SchedulePhase {
+    pub const LEN: usize = 8 + 1 + EquationParams::LEN;
+}
+
+/// Mint-level chain of curves, each phase starting from the balance the previous one ended on
+#[account]
+pub struct EquationSchedule {
+    pub mint: Pubkey,
+    pub phases: Vec<SchedulePhase>,
+    pub bump: u8,
+}
+
+impl EquationSchedule {
+    pub const LEN: usize = 8 + 32 + 4 + MAX_SCHEDULE_PHASES * SchedulePhase::LEN + 1;
+
+    pub fn validate(phases: &mut [SchedulePhase], time_base: TimeBase) -> Result<()> {
+        if phases.is_empty() || phases.len() > MAX_SCHEDULE_PHASES {
+            return Err(TokenError::InvalidSchedule.into());
+        }
+        if phases[0].start_offset < 0 || phases.windows(2).any(|pair| pair[0].start_offset >= pair[1].start_offset) {
+            msg!("Schedule phases must start at non-negative, strictly increasing offsets");
+            return Err(TokenError::InvalidSchedule.into());
+        }
+        for phase in phases.iter_mut() {
+            // Expiry and vesting locks are per account, they cannot be chained
+            if matches!(phase.equation_type, ChronoEquationType::Subscription | ChronoEquationType::Vesting) {
+                msg!("{:?} cannot be used as a schedule phase", phase.equation_type);
+                return Err(TokenError::InvalidSchedule.into());
+            }
+            phase.params.apply_half_life(time_base)?;
+            phase.equation_type.validate_params(&phase.params, time_base)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Governance snapshot of a mint's balances at `snapshot_time`
+#[account]
+pub struct Snapshot {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub snapshot_id: u64,
+    pub snapshot_time: i64,
+    /// Balances can be recorded from `snapshot_time` until this time
+    pub record_deadline: i64,
+    pub recorded_accounts: u64,
+    pub recorded_total: u64,
+    pub bump: u8,
+}
+
+impl Snapshot {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
+/// An account's evaluated balance at a snapshot's time
+#[account]
+pub struct BalanceRecord {
+    pub snapshot: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub balance: u64,
+    pub recorded_at: i64,
+    pub bump: u8,
+}
+
+impl BalanceRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+}
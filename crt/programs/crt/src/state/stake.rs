@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+/// Scale of `StakePool::reward_per_share`
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+pub const MAX_HARVEST_SOURCES: usize = 16;
+
+/// Mint-level staking pool, stakers share the decay harvested from the accounts whose owners
+/// opted them in. Harvested decay is released to stakers evenly over `reward_duration`.
+#[account]
+pub struct StakePool {
+    pub mint: Pubkey,
+    /// Program-owned token account holding staked tokens, held flat at `total_staked`
+    pub vault: Pubkey,
+    pub total_staked: u64,
+    /// Rewards per staked token so far, scaled by `REWARD_PRECISION`
+    pub reward_per_share: u128,
+    pub total_harvested: u64,
+    pub total_claimed: u64,
+    /// Time harvested rewards are released over
+    pub reward_duration: i64,
+    /// Harvested rewards not released to stakers yet
+    pub undistributed: u64,
+    /// Time rewards were last released up to
+    pub last_release: i64,
+    /// Time `undistributed` is fully released by
+    pub release_end: i64,
+    /// Token accounts whose decay pools can be harvested
+    pub harvest_sources: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl StakePool {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 8 + 8 + 8 + 8 + 8 + 8 + (4 + MAX_HARVEST_SOURCES * 32) + 1;
+
+    /// Adds harvested decay to what is being released, restarting the release over `reward_duration`
+    pub fn add_rewards(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        self.release(current_time)?;
+        self.undistributed = self.undistributed.checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+        self.total_harvested = self.total_harvested.checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+        self.last_release = current_time;
+        self.release_end = current_time.saturating_add(self.reward_duration);
+        Ok(())
+    }
+
+    /// Releases the share of `undistributed` due by `current_time` to the current stake.
+    /// While nothing is staked the release is pushed back instead.
+    pub fn release(&mut self, current_time: i64) -> Result<()> {
+        let until = current_time.min(self.release_end);
+        if until <= self.last_release {
+            return Ok(());
+        }
+        let elapsed = until - self.last_release;
+        if self.total_staked == 0 {
+            self.release_end = self.release_end.saturating_add(elapsed);
+            self.last_release = until;
+            return Ok(());
+        }
+
+        let remaining = self.release_end - self.last_release;
+        let amount = (self.undistributed as u128 * elapsed as u128 / remaining as u128) as u64;
+        self.distribute(amount)?;
+        self.undistributed -= amount;
+        self.last_release = until;
+        Ok(())
+    }
+
+    /// Spreads `amount` over the current stake
+    fn distribute(&mut self, amount: u64) -> Result<()> {
+        if self.total_staked == 0 {
+            return Err(TokenError::NothingStaked.into());
+        }
+        let per_share = (amount as u128)
+            .checked_mul(REWARD_PRECISION)
+            .ok_or(TokenError::Overflow)?
+            / self.total_staked as u128;
+        self.reward_per_share = self.reward_per_share.checked_add(per_share)
+            .ok_or(TokenError::Overflow)?;
+        Ok(())
+    }
+
+    /// Rewards `amount` staked tokens have earned since the pool opened
+    pub fn rewards_for(&self, amount: u64) -> Result<u128> {
+        (amount as u128)
+            .checked_mul(self.reward_per_share)
+            .map(|rewards| rewards / REWARD_PRECISION)
+            .ok_or_else(|| TokenError::Overflow.into())
+    }
+}
+
+/// A token account's stake, staked tokens return to the same account
+#[account]
+pub struct StakePosition {
+    pub stake_pool: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    /// `rewards_for(amount)` at the last change of `amount`
+    pub reward_debt: u128,
+    /// Rewards earned but not yet claimed
+    pub pending_rewards: u64,
+    pub bump: u8,
+}
+
+impl StakePosition {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 16 + 8 + 1;
+
+    /// Moves rewards earned since the last update into `pending_rewards`
+    pub fn accrue(&mut self, stake_pool: &StakePool) -> Result<()> {
+        let earned = stake_pool.rewards_for(self.amount)?.saturating_sub(self.reward_debt);
+        self.pending_rewards = u64::try_from(earned)
+            .ok()
+            .and_then(|earned| self.pending_rewards.checked_add(earned))
+            .ok_or(TokenError::Overflow)?;
+        self.reward_debt = stake_pool.rewards_for(self.amount)?;
+        Ok(())
+    }
+}
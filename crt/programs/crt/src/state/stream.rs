@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+/// Continuous payment from one token account to another. Both accounts carry a `StreamLeg`
+/// so `evaluate_balance` moves the streamed amount out of the sender's balance and into the
+/// recipient's as time passes. Until it streams out, the deposit stays in the sender's balance
+/// with decay suspended and cannot be spent.
+#[account]
+pub struct Stream {
+    pub mint: Pubkey,
+    pub sender: Pubkey,
+    pub sender_token_account: Pubkey,
+    pub recipient: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub stream_id: u64,
+    /// Amount streamed per unit of the mint's time base
+    pub rate_per_unit: u64,
+    pub start_time: i64,
+    /// Moved back to the cancellation time when the stream is cancelled
+    pub end_time: i64,
+    pub deposited: u64,
+    pub withdrawn: u64,
+    pub cancelled: bool,
+    pub bump: u8,
+}
+
+impl Stream {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    /// Deposit a stream needs to pay `rate_per_unit` from `start_time` to `end_time`
+    pub fn deposit_for(rate_per_unit: u64, start_time: i64, end_time: i64) -> Result<u64> {
+        if rate_per_unit == 0 || end_time <= start_time {
+            return Err(TokenError::InvalidStream.into());
+        }
+        (end_time - start_time)
+            .try_into()
+            .ok()
+            .and_then(|duration: u64| duration.checked_mul(rate_per_unit))
+            .ok_or_else(|| TokenError::Overflow.into())
+    }
+
+    /// Total streamed to the recipient by `current_time`, withdrawn or not
+    pub fn streamed(&self, current_time: i64) -> u64 {
+        let elapsed = current_time.clamp(self.start_time, self.end_time) - self.start_time;
+        (elapsed as u64).saturating_mul(self.rate_per_unit).min(self.deposited)
+    }
+
+    /// Streamed but not yet withdrawn
+    pub fn withdrawable(&self, current_time: i64) -> u64 {
+        self.streamed(current_time).saturating_sub(self.withdrawn)
+    }
+}
+
+/// One side of a stream, kept on both token accounts so their balances can be evaluated
+/// without the `Stream` account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct StreamLeg {
+    pub stream: Pubkey,
+    pub rate_per_unit: u64,
+    pub start_time: i64,
+    /// Moved back to the cancellation time when the stream is cancelled
+    pub end_time: i64,
+    /// Whether the account pays the stream rather than receiving it
+    pub outgoing: bool,
+}
+
+impl StreamLeg {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 1;
+
+    /// Amount streamed between two times
+    pub fn streamed_between(&self, from: i64, to: i64) -> u64 {
+        let start = from.max(self.start_time);
+        let end = to.min(self.end_time);
+        if end <= start {
+            return 0;
+        }
+        ((end - start) as u64).saturating_mul(self.rate_per_unit)
+    }
+}
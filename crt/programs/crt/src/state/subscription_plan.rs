@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+pub const BASIS_POINTS: u16 = 10_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum RefundPolicy {
+    None,
+    ProRata,
+    /// Pro-rata refund with `fee_bps` of it kept by the merchant
+    ProRataMinusFee { fee_bps: u16 },
+}
+
+#[account]
+pub struct SubscriptionPlan {
+    pub merchant: Pubkey,
+    pub mint: Pubkey,
+    /// Token account that receives subscription payments
+    pub merchant_token_account: Pubkey,
+    /// Program-owned token account that funds cancellation refunds
+    pub escrow_token_account: Pubkey,
+    pub plan_id: u64,
+    /// Amount charged for each period
+    pub price: u64,
+    /// Time added to the subscriber's expiry on each renewal
+    pub period: i64,
+    /// Time after expiry before the subscriber's balance lapses
+    pub grace_period: i64,
+    /// Maximum renewals per subscriber, 0 for unlimited
+    pub max_renewals: u32,
+    pub refund_policy: RefundPolicy,
+    pub bump: u8,
+}
+
+impl SubscriptionPlan {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 4 + (1 + 2) + 1;
+
+    /// Refund owed for all prepaid time left on the plan, time carried over from a
+    /// previous plan was paid to that plan's merchant and is not refunded here
+    pub fn refund_for(&self, subscription_expiry: i64, carried_until: i64, current_time: i64) -> Result<u64> {
+        let unexpired = subscription_expiry
+            .saturating_sub(current_time.max(carried_until))
+            .max(0);
+        let pro_rata = (self.price as u128)
+            .checked_mul(unexpired as u128)
+            .and_then(|amount| amount.checked_div(self.period as u128))
+            .ok_or(TokenError::Overflow)? as u64;
+
+        Ok(match self.refund_policy {
+            RefundPolicy::None => 0,
+            RefundPolicy::ProRata => pro_rata,
+            RefundPolicy::ProRataMinusFee { fee_bps } => {
+                let fee = (pro_rata as u128 * fee_bps as u128 / BASIS_POINTS as u128) as u64;
+                pro_rata.saturating_sub(fee)
+            }
+        })
+    }
+}
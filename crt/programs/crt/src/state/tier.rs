@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use super::{ChronoEquationType, EquationParams, TimeBase};
+
+pub const MAX_TIERS: usize = 8;
+pub const MAX_TIER_NAME_LEN: usize = 16;
+
+/// Named curve a mint authority can assign to individual accounts, e.g. "exempt" or "whale"
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct Tier {
+    pub name: String,
+    pub equation_type: ChronoEquationType,
+    pub params: EquationParams,
+}
+
+impl Tier {
+    pub const LEN: usize = 4 + MAX_TIER_NAME_LEN + 1 + EquationParams::LEN;
+}
+
+/// Mint-level tier table, accounts reference a tier by its index
+#[account]
+pub struct TierTable {
+    pub mint: Pubkey,
+    pub tiers: Vec<Tier>,
+    pub bump: u8,
+}
+
+impl TierTable {
+    pub const LEN: usize = 8 + 32 + 4 + MAX_TIERS * Tier::LEN + 1;
+
+    pub fn validate(tiers: &mut [Tier], time_base: TimeBase) -> Result<()> {
+        if tiers.is_empty() || tiers.len() > MAX_TIERS {
+            return Err(TokenError::InvalidTierTable.into());
+        }
+        for (index, tier) in tiers.iter().enumerate() {
+            if tier.name.is_empty() || tier.name.len() > MAX_TIER_NAME_LEN {
+                msg!("Tier names must be 1 to {} bytes", MAX_TIER_NAME_LEN);
+                return Err(TokenError::InvalidTierTable.into());
+            }
+            if tiers[..index].iter().any(|other| other.name == tier.name) {
+                msg!("Duplicate tier name '{}'", tier.name);
+                return Err(TokenError::InvalidTierTable.into());
+            }
+        }
+        for tier in tiers.iter_mut() {
+            tier.params.apply_half_life(time_base)?;
+            tier.equation_type.validate_params(&tier.params, time_base)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Clock a mint measures time in. Every timestamp and duration on the mint's accounts,
+/// curve params and plans is expressed in this unit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub enum TimeBase {
+    #[default]
+    UnixTimestamp,
+    Slot,
+    Epoch,
+}
+
+impl TimeBase {
+    pub fn now(&self, clock: &Clock) -> i64 {
+        match self {
+            TimeBase::UnixTimestamp => clock.unix_timestamp,
+            TimeBase::Slot => clock.slot as i64,
+            TimeBase::Epoch => clock.epoch as i64,
+        }
+    }
+
+    /// Converts a wall-clock duration into this time base, at the cluster's target
+    /// 400ms slots and 432,000 slots per epoch
+    pub fn duration_from_seconds(&self, seconds: i64) -> i64 {
+        match self {
+            TimeBase::UnixTimestamp => seconds,
+            TimeBase::Slot => seconds.saturating_mul(5) / 2,
+            TimeBase::Epoch => seconds / 172_800,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use super::{AccountState, ChronoEquationType};
+use super::{AccountState, ChronoEquationType, StreamLeg};
 
 #[account]
 pub struct TokenAccount {
@@ -17,7 +17,42 @@ pub struct TokenAccount {
     pub delegated_amount: u64,
     /// CHECK: This is safe because SafeOptionPubkey is a custom type that safely represents an optional Pubkey.
     /// The close authority is only used when closing the account, which is checked separately.
-    pub close_authority: Option<Pubkey>
+    pub close_authority: Option<Pubkey>,
+    /// The time at which `last_balance_snapshot` was last settled
+    pub last_update_time: i64,
+    /// The time of the last ReUp, used for cooldowns and streaks
+    pub last_reup_time: i64,
+    /// Number of consecutive ReUps inside the mint's ReUp policy window
+    pub reup_streak: u32,
+    /// The subscription plan this account last renewed, default if never subscribed
+    pub subscription_plan: Pubkey,
+    /// End of the paid subscription period, 0 while a subscription awaits its first collection
+    pub subscription_expiry: i64,
+    /// Expiry carried over from a previous plan, time up to it was paid to that plan's merchant
+    pub subscription_carried_until: i64,
+    /// Grace period of the plan at the last renewal
+    pub subscription_grace_period: i64,
+    /// Number of renewals of `subscription_plan`
+    pub subscription_renewals: u32,
+    /// Whether the plan's merchant may collect the next period from this account
+    pub subscription_auto_renew: bool,
+    /// Plan this account last cancelled with a refund, it can no longer subscribe to it
+    pub cancelled_plan: Pubkey,
+    /// Amount minted into this account while it follows a vesting curve, locked by the mint's vesting schedule
+    pub vesting_total: u64,
+    /// Balance the mint's floor is measured against, growth and deposits raise it and only
+    /// withdrawals lower it, so settling realized decay cannot wear the floor down
+    pub floor_basis: u64,
+    /// Snapshot time of the mint the last checkpoint was taken at
+    pub checkpoint_time: i64,
+    /// Balance at `checkpoint_time`, written by the first settlement past it
+    pub checkpoint_balance: u64,
+    /// Live time the account has settled along its current curve, logistic decay runs along it
+    pub curve_age: i64,
+    /// Index into the mint's tier table, the mint's own curve applies when unset
+    pub tier: Option<u8>,
+    /// Streams paying into or out of this account, dropped once a settlement passes their end
+    pub streams: Vec<StreamLeg>,
 }
 
 impl TokenAccount {
@@ -31,11 +66,66 @@ impl TokenAccount {
         32 + // delegate
         4 + 200 + // delegated_amount (4 bytes for length + max 200 bytes for content)
         1 + 32 + // close_authority
+        8 + // last_update_time
+        8 + // last_reup_time
+        4 + // reup_streak
+        32 + // subscription_plan
+        8 + // subscription_expiry
+        8 + // subscription_carried_until
+        8 + // subscription_grace_period
+        4 + // subscription_renewals
+        1 + // subscription_auto_renew
+        32 + // cancelled_plan
+        8 + // vesting_total
+        8 + // floor_basis
+        8 + // checkpoint_time
+        8 + // checkpoint_balance
+        8 + // curve_age
+        1 + 1 + // tier
+        4 + // streams, grown by `create_stream`
         1; // equation_type
 
+    /// Space for an account carrying `streams` stream legs
+    pub fn space(streams: usize) -> usize {
+        Self::LEN + streams * StreamLeg::LEN
+    }
+
 
     /// Checks if account is frozen
     pub fn is_frozen(&self) -> bool {
         self.state == AccountState::Frozen
     }
+
+    /// Checks if account is a program vault, which is held flat
+    pub fn is_vault(&self) -> bool {
+        self.state == AccountState::Vault
+    }
+
+    /// Amounts streamed into and out of the account between two times
+    pub fn streamed_between(&self, from: i64, to: i64) -> (u64, u64) {
+        self.streams.iter().fold((0, 0), |(streamed_in, streamed_out), leg| {
+            let amount = leg.streamed_between(from, to);
+            if leg.outgoing {
+                (streamed_in, streamed_out.saturating_add(amount))
+            } else {
+                (streamed_in.saturating_add(amount), streamed_out)
+            }
+        })
+    }
+
+    /// Part of the balance outgoing streams still pay out after `time`
+    pub fn stream_commitment(&self, time: i64) -> u64 {
+        self.streams.iter()
+            .filter(|leg| leg.outgoing)
+            .map(|leg| leg.streamed_between(time, leg.end_time))
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Time after which a subscription balance lapses, if the account has a paid subscription
+    pub fn subscription_cutoff(&self) -> Option<i64> {
+        if self.subscription_plan == Pubkey::default() || self.subscription_expiry == 0 {
+            return None;
+        }
+        Some(self.subscription_expiry.saturating_add(self.subscription_grace_period))
+    }
 }
\ No newline at end of file
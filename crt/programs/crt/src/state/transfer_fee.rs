@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use super::BASIS_POINTS;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum FeeDestination {
+    /// Fees fund the sender's own ReUps
+    SenderDecayPool,
+    /// Fees accumulate in the mint's `FeeVault` until the fee authority withdraws them
+    FeeVault,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct TransferFeeConfig {
+    pub fee_bps: u16,
+    /// Cap on the fee of a single transfer
+    pub max_fee: u64,
+    pub destination: FeeDestination,
+    pub fee_authority: Pubkey,
+}
+
+impl TransferFeeConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.fee_bps > BASIS_POINTS {
+            return Err(TokenError::InvalidTransferFee.into());
+        }
+        Ok(())
+    }
+
+    /// Fee withheld from a transfer of `amount`
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        let fee = (amount as u128 * self.fee_bps as u128 / BASIS_POINTS as u128) as u64;
+        fee.min(self.max_fee)
+    }
+}
+
+/// Mint-level balance of withheld transfer fees
+#[account]
+pub struct FeeVault {
+    pub mint: Pubkey,
+    pub withheld: u64,
+    pub bump: u8,
+}
+
+impl FeeVault {
+    pub const LEN: usize = 8 + 32 + 8 + 1;
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use std::collections::HashMap;
+use crate::error::TokenError;
 
 #[derive(Debug, Clone)]
 pub enum Token {
@@ -13,7 +14,8 @@ pub enum Token {
 
 #[derive(Debug)]
 pub struct Parser {
-    tokens: Vec<Token>,
+    /// Tokens paired with their character position in the input, for diagnostics
+    tokens: Vec<(Token, usize)>,
     variables: HashMap<String, f64>,
 }
 
@@ -26,45 +28,54 @@ impl Parser {
         })
     }
 
-    fn tokenize(input: &str) -> Result<Vec<Token>> {
+    fn tokenize(input: &str) -> Result<Vec<(Token, usize)>> {
         let mut tokens = Vec::new();
-        let mut chars = input.chars().peekable();
+        let mut chars = input.chars().enumerate().peekable();
 
-        while let Some(ch) = chars.next() {
+        while let Some((pos, ch)) = chars.next() {
             match ch {
                 '0'..='9' | '.' => {
                     let mut num = String::new();
                     num.push(ch);
-                    while let Some(&next_ch) = chars.peek() {
-                        if next_ch.is_digit(10) || next_ch == '.' {
-                            num.push(chars.next().unwrap());
+                    while let Some(&(_, next_ch)) = chars.peek() {
+                        if next_ch.is_ascii_digit() || next_ch == '.' {
+                            num.push(next_ch);
+                            chars.next();
                         } else {
                             break;
                         }
                     }
-                    tokens.push(Token::Number(num.parse().map_err(|_| error!(ErrorCode::InvalidNumber))?));
+                    let value = num.parse().map_err(|_| {
+                        msg!("Invalid number '{}' at position {}", num, pos);
+                        error!(TokenError::ExpressionParseError)
+                    })?;
+                    tokens.push((Token::Number(value), pos));
                 },
-                '+' | '-' | '*' | '/' | '^' => tokens.push(Token::Operator(ch)),
-                '(' => tokens.push(Token::LeftParen),
-                ')' => tokens.push(Token::RightParen),
+                '+' | '-' | '*' | '/' | '^' => tokens.push((Token::Operator(ch), pos)),
+                '(' => tokens.push((Token::LeftParen, pos)),
+                ')' => tokens.push((Token::RightParen, pos)),
                 'a'..='z' | 'A'..='Z' => {
                     let mut name = String::new();
                     name.push(ch);
-                    while let Some(&next_ch) = chars.peek() {
-                        if next_ch.is_alphanumeric() {
-                            name.push(chars.next().unwrap());
+                    while let Some(&(_, next_ch)) = chars.peek() {
+                        if next_ch.is_alphanumeric() || next_ch == '_' {
+                            name.push(next_ch);
+                            chars.next();
                         } else {
                             break;
                         }
                     }
-                    if chars.peek() == Some(&'(') {
-                        tokens.push(Token::Function(name));
+                    if matches!(chars.peek(), Some((_, '('))) {
+                        tokens.push((Token::Function(name), pos));
                     } else {
-                        tokens.push(Token::Variable(name));
+                        tokens.push((Token::Variable(name), pos));
                     }
                 },
                 ' ' => {},
-                _ => return Err(error!(ErrorCode::InvalidCharacter)),
+                _ => {
+                    msg!("Invalid character '{}' at position {}", ch, pos);
+                    return Err(error!(TokenError::ExpressionParseError));
+                }
             }
         }
         Ok(tokens)
@@ -78,29 +89,29 @@ impl Parser {
         let mut output_queue = Vec::new();
         let mut operator_stack = Vec::new();
 
-        for token in &self.tokens {
-            match token {
-                Token::Number(_) | Token::Variable(_) => output_queue.push(token.clone()),
-                Token::Function(_) => operator_stack.push(token.clone()),
+        for entry in &self.tokens {
+            match &entry.0 {
+                Token::Number(_) | Token::Variable(_) => output_queue.push(entry.clone()),
+                Token::Function(_) => operator_stack.push(entry.clone()),
                 Token::Operator(op) => {
-                    while let Some(Token::Operator(top)) = operator_stack.last() {
+                    while let Some((Token::Operator(top), _)) = operator_stack.last() {
                         if Self::precedence(*top) >= Self::precedence(*op) {
                             output_queue.push(operator_stack.pop().unwrap());
                         } else {
                             break;
                         }
                     }
-                    operator_stack.push(token.clone());
+                    operator_stack.push(entry.clone());
                 },
-                Token::LeftParen => operator_stack.push(token.clone()),
+                Token::LeftParen => operator_stack.push(entry.clone()),
                 Token::RightParen => {
                     while let Some(top) = operator_stack.pop() {
-                        if let Token::LeftParen = top {
+                        if let Token::LeftParen = top.0 {
                             break;
                         }
                         output_queue.push(top);
                     }
-                    if let Some(Token::Function(_)) = operator_stack.last() {
+                    if let Some((Token::Function(_), _)) = operator_stack.last() {
                         output_queue.push(operator_stack.pop().unwrap());
                     }
                 },
@@ -112,42 +123,75 @@ impl Parser {
         }
 
         let mut stack = Vec::new();
-        for token in output_queue {
+        for (token, pos) in output_queue {
             match token {
                 Token::Number(num) => stack.push(num),
                 Token::Variable(name) => {
-                    let value = self.variables.get(&name).ok_or(error!(ErrorCode::UndefinedVariable))?;
+                    let value = self.variables.get(&name).ok_or_else(|| {
+                        msg!("Undefined variable '{}' at position {}", name, pos);
+                        error!(TokenError::UndefinedVariable)
+                    })?;
                     stack.push(*value);
                 },
                 Token::Operator(op) => {
-                    let b = stack.pop().ok_or(error!(ErrorCode::InvalidExpression))?;
-                    let a = stack.pop().ok_or(error!(ErrorCode::InvalidExpression))?;
+                    let b = Self::pop_operand(&mut stack, pos)?;
+                    let a = Self::pop_operand(&mut stack, pos)?;
                     let result = match op {
                         '+' => a + b,
                         '-' => a - b,
                         '*' => a * b,
-                        '/' => a / b,
+                        '/' => {
+                            if b == 0.0 {
+                                msg!("Division by zero at position {}", pos);
+                                return Err(error!(TokenError::DivisionByZero));
+                            }
+                            a / b
+                        },
                         '^' => a.powf(b),
-                        _ => return Err(error!(ErrorCode::UnsupportedOperator)),
+                        _ => {
+                            msg!("Unsupported operator '{}' at position {}", op, pos);
+                            return Err(error!(TokenError::ExpressionParseError));
+                        }
                     };
                     stack.push(result);
                 },
                 Token::Function(name) => {
-                    let arg = stack.pop().ok_or(error!(ErrorCode::InvalidExpression))?;
+                    let arg = Self::pop_operand(&mut stack, pos)?;
                     let result = match name.as_str() {
                         "exp" => arg.exp(),
                         "ln" => arg.ln(),
                         "sin" => arg.sin(),
                         "cos" => arg.cos(),
-                        _ => return Err(error!(ErrorCode::UnsupportedFunction)),
+                        _ => {
+                            msg!("Unsupported function '{}' at position {}", name, pos);
+                            return Err(error!(TokenError::ExpressionParseError));
+                        }
                     };
                     stack.push(result);
                 },
-                _ => return Err(error!(ErrorCode::InvalidExpression)),
+                _ => {
+                    msg!("Unbalanced parenthesis at position {}", pos);
+                    return Err(error!(TokenError::ExpressionParseError));
+                }
             }
         }
 
-        stack.pop().ok_or(error!(ErrorCode::InvalidExpression))
+        let result = stack.pop().ok_or_else(|| {
+            msg!("Empty expression");
+            error!(TokenError::ExpressionParseError)
+        })?;
+        if !stack.is_empty() {
+            msg!("Expression leaves {} unused operands", stack.len());
+            return Err(error!(TokenError::ExpressionParseError));
+        }
+        Ok(result)
+    }
+
+    fn pop_operand(stack: &mut Vec<f64>, pos: usize) -> Result<f64> {
+        stack.pop().ok_or_else(|| {
+            msg!("Missing operand at position {}", pos);
+            error!(TokenError::ExpressionParseError)
+        })
     }
 
     fn precedence(op: char) -> u8 {
@@ -159,19 +203,3 @@ impl Parser {
         }
     }
 }
-
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Invalid number in expression")]
-    InvalidNumber,
-    #[msg("Invalid character in expression")]
-    InvalidCharacter,
-    #[msg("Undefined variable")]
-    UndefinedVariable,
-    #[msg("Invalid expression")]
-    InvalidExpression,
-    #[msg("Unsupported operator")]
-    UnsupportedOperator,
-    #[msg("Unsupported function")]
-    UnsupportedFunction,
-}
\ No newline at end of file
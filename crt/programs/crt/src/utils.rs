@@ -1,28 +1,196 @@
 use anchor_lang::prelude::*;
 use crate::error::TokenError;
-use crate::state::ChronoEquationType;
+use crate::state::{
+    ChronoEquationType, DecayPause, DecayPool, EquationParams, EquationSchedule, ExemptionRegistry, SchedulePhase, Tier, TierTable,
+    TokenAccount,
+};
+use crate::extensions::ChronoExtension;
 use crate::tokenizer::Parser;  // Import the custom parser
 
 const TOKEN_DECIMALS: u8 = 9;
-const DECIMALS_FACTOR: u64 = 10u64.pow(TOKEN_DECIMALS as u32);
+pub const DECIMALS_FACTOR: u64 = 10u64.pow(TOKEN_DECIMALS as u32);
 
+/// Mint-level curve configuration every balance evaluation reads
+pub struct CurveConfig<'a> {
+    pub params: &'a EquationParams,
+    pub schedule: Option<&'a [SchedulePhase]>,
+    pub tiers: Option<&'a [Tier]>,
+    /// Mint pauses that stopped decay
+    pub decay_pauses: &'a [DecayPause],
+    /// Time of the mint's latest snapshot, settlements crossing it checkpoint the balance
+    pub snapshot_time: Option<i64>,
+}
+
+impl<'a> CurveConfig<'a> {
+    pub fn load(
+        extension: &'a ChronoExtension,
+        schedule: Option<&'a EquationSchedule>,
+        tier_table: Option<&'a TierTable>,
+    ) -> Result<Self> {
+        if extension.has_schedule && schedule.is_none() {
+            return Err(TokenError::MissingSchedule.into());
+        }
+        Ok(Self {
+            params: &extension.equation_params,
+            schedule: schedule.filter(|_| extension.has_schedule).map(|schedule| schedule.phases.as_slice()),
+            tiers: tier_table.map(|tier_table| tier_table.tiers.as_slice()),
+            decay_pauses: &extension.decay_pauses,
+            snapshot_time: extension.snapshot_time,
+        })
+    }
+
+    /// Moves `last_update_time` forward by every decay pause it has not settled yet,
+    /// so curves only run for the time the mint was live
+    pub fn decay_start(&self, last_update_time: i64, current_time: i64) -> i64 {
+        let paused: i64 = self.decay_pauses.iter()
+            .map(|pause| pause.overlap(last_update_time, current_time))
+            .fold(0, i64::saturating_add);
+        last_update_time.saturating_add(paused)
+    }
+
+    pub fn tier(&self, index: u8) -> Result<&'a Tier> {
+        let tiers = self.tiers.ok_or(TokenError::MissingTierTable)?;
+        tiers.get(index as usize).ok_or_else(|| TokenError::UnknownTier.into())
+    }
+
+    /// Params an account's curve reads, its tier's when it has one
+    pub fn params_for(&self, token_account: &TokenAccount) -> Result<&'a EquationParams> {
+        match token_account.tier {
+            Some(index) => Ok(&self.tier(index)?.params),
+            None => Ok(self.params),
+        }
+    }
+}
+
+/// Evaluates an account's balance at `current_time` from its last settled snapshot,
+/// including what its streams moved since then
 pub fn evaluate_balance(
+    token_account: &TokenAccount,
+    config: &CurveConfig,
+    current_time: i64,
+) -> Result<u64> {
+    let last_update_time = token_account.last_update_time;
+    // What outgoing streams still owe is held flat, only the rest of the snapshot follows the curve
+    let committed = token_account.stream_commitment(last_update_time);
+    let last_balance_snapshot = token_account.last_balance_snapshot.saturating_sub(committed);
+    let equation_type = &token_account.current_chrono_equation;
+    let params = config.params_for(token_account)?;
+
+    // A lapsed subscription is realized once, at the first settlement after the cutoff
+    let evaluated_balance = if *equation_type == ChronoEquationType::Subscription {
+        match token_account.subscription_cutoff() {
+            Some(cutoff) if last_update_time <= cutoff && current_time > cutoff => 0,
+            _ => last_balance_snapshot,
+        }
+    } else if let (None, Some(phases)) = (token_account.tier, config.schedule) {
+        evaluate_schedule(
+            last_balance_snapshot,
+            phases,
+            token_account.creation_time,
+            config.decay_start(last_update_time, current_time),
+            current_time
+        )?
+    } else {
+        let decay_start = config.decay_start(last_update_time, current_time);
+        evaluate_curve(last_balance_snapshot, equation_type, params, token_account.curve_age, decay_start, current_time)?
+    };
+
+    let evaluated_balance = evaluated_balance.checked_add(committed)
+        .ok_or(TokenError::Overflow)?;
+    let bounded_balance = params.bound(token_account.floor_basis, token_account.last_balance_snapshot, evaluated_balance);
+    apply_streams(token_account, bounded_balance, current_time)
+}
+
+/// Moves a balance along the account's streams from its last settlement to `current_time`,
+/// adding what streamed in and removing what streamed out
+pub fn apply_streams(token_account: &TokenAccount, balance: u64, current_time: i64) -> Result<u64> {
+    let (streamed_in, streamed_out) = token_account.streamed_between(token_account.last_update_time, current_time);
+    balance.checked_add(streamed_in)
+        .and_then(|balance| balance.checked_sub(streamed_out))
+        .ok_or_else(|| TokenError::Overflow.into())
+}
+
+/// Evaluates an account's balance, held flat at its snapshot when it is decay exempt.
+/// Streams still move an exempt balance.
+pub fn evaluate_balance_unless_exempt(
+    token_account: &TokenAccount,
+    exempt: bool,
+    config: &CurveConfig,
+    current_time: i64,
+) -> Result<u64> {
+    if exempt {
+        return apply_streams(token_account, token_account.last_balance_snapshot, current_time);
+    }
+    evaluate_balance(token_account, config, current_time)
+}
+
+/// Whether `token_account` is held flat, either as a program vault or through the mint's
+/// exemption registry. A mint with a registry must always provide it.
+pub fn is_exempt(
+    extension: &ChronoExtension,
+    registry: Option<&ExemptionRegistry>,
+    token_account: &Account<TokenAccount>,
+    owner: Option<&AccountInfo>,
+) -> Result<bool> {
+    if token_account.is_vault() {
+        return Ok(true);
+    }
+    match registry {
+        Some(registry) if extension.has_exemption_registry => registry.is_exempt(&token_account.key(), owner),
+        None if extension.has_exemption_registry => Err(TokenError::MissingExemptionRegistry.into()),
+        _ => Ok(false),
+    }
+}
+
+/// Chains schedule phases between two times, each phase's ending balance is the next one's `x`.
+/// Before the first phase starts the balance is flat.
+pub fn evaluate_schedule(
+    last_balance_snapshot: u64,
+    phases: &[SchedulePhase],
+    origin: i64,
+    last_update_time: i64,
+    current_time: i64,
+) -> Result<u64> {
+    let mut balance = last_balance_snapshot;
+    for (index, phase) in phases.iter().enumerate() {
+        let phase_start = origin.saturating_add(phase.start_offset);
+        let phase_end = phases.get(index + 1)
+            .map(|next| origin.saturating_add(next.start_offset))
+            .unwrap_or(i64::MAX);
+
+        let segment_start = phase_start.max(last_update_time);
+        let segment_end = phase_end.min(current_time);
+        if segment_start >= segment_end {
+            continue;
+        }
+
+        let age = segment_start.saturating_sub(phase_start);
+        balance = evaluate_curve(balance, &phase.equation_type, &phase.params, age, segment_start, segment_end)?;
+    }
+    Ok(balance)
+}
+
+/// Evaluates a single curve over `current_time - last_update_time` starting from `x`,
+/// `curve_age` is how far along the curve `x` was settled
+pub fn evaluate_curve(
     last_balance_snapshot: u64,
     equation_type: &ChronoEquationType,
-    creation_time: i64,
-    current_time: i64
+    params: &EquationParams,
+    curve_age: i64,
+    last_update_time: i64,
+    current_time: i64,
 ) -> Result<u64> {
-    let params = equation_type.get_params();
     let equation = equation_type.get_equation_string();
     if equation == "x" {
         return Ok(last_balance_snapshot);
     }
-    let time_diff = current_time.saturating_sub(creation_time) as f64;
+    let time_diff = current_time.saturating_sub(last_update_time) as f64;
     let last_snapshot_f = last_balance_snapshot as f64 / DECIMALS_FACTOR as f64;
 
     let mut parser = Parser::new(&equation)?;
     parser.set_variable("x", last_snapshot_f);
     parser.set_variable("t", time_diff);
+    parser.set_variable("age", curve_age as f64);
 
     // Set parameter variables
     if let Some(expiration_time) = params.expiration_time {
@@ -43,13 +211,511 @@ pub fn evaluate_balance(
     if let Some(decay_constant) = params.decay_constant {
         parser.set_variable("decay_constant", decay_constant);
     }
+    if let Some(midpoint) = params.midpoint {
+        parser.set_variable("midpoint", midpoint as f64);
+    }
+    if let Some(steepness) = params.steepness {
+        parser.set_variable("steepness", steepness);
+    }
+
+    let result = parser.evaluate()?;
+    if !result.is_finite() {
+        msg!("Equation {:?} evaluated to {} at t = {}", equation_type, result, time_diff);
+        return Err(TokenError::NonFiniteBalance.into());
+    }
+
+    // Curves that run past zero (deflationary) leave an empty balance, the mint's floor is applied after
+    Ok((result.max(0.0) * DECIMALS_FACTOR as f64).round() as u64)
+}
+
+/// Part of an evaluated balance that cannot leave the account yet, what has not vested
+/// and what outgoing streams still owe
+pub fn locked_balance(
+    token_account: &TokenAccount,
+    params: &EquationParams,
+    balance: u64,
+    current_time: i64,
+) -> u64 {
+    let unvested = match token_account.current_chrono_equation {
+        ChronoEquationType::Vesting => params.locked_amount(token_account.vesting_total, balance, current_time),
+        _ => 0,
+    };
+    unvested.saturating_add(token_account.stream_commitment(current_time)).min(balance)
+}
+
+/// Decay realized between a settled snapshot and its evaluated balance.
+/// Growth (inflationary or linear curves) realizes no decay.
+pub fn realized_decay(last_balance_snapshot: u64, evaluated_balance: u64) -> u64 {
+    last_balance_snapshot.saturating_sub(evaluated_balance)
+}
+
+/// Settles an account at `current_time`, crediting the decay realized since its last
+/// settlement to its own pool so that snapshot plus pool is conserved.
+/// A settlement that crosses the mint's snapshot time first checkpoints the balance at it.
+/// Streamed amounts are not decay, only what the curve took off the streamed balance is.
+pub fn settle(
+    token_account: &mut TokenAccount,
+    decay_pool: &mut DecayPool,
+    evaluated_balance: u64,
+    new_balance: u64,
+    config: &CurveConfig,
+    current_time: i64,
+) -> Result<()> {
+    let streamed_balance = apply_streams(token_account, token_account.last_balance_snapshot, current_time)?;
+
+    if let Some(snapshot_time) = config.snapshot_time {
+        if token_account.last_update_time <= snapshot_time && snapshot_time < current_time {
+            // Curves are monotonic, a balance that held flat until now (exempt or not) held at the snapshot too
+            token_account.checkpoint_balance = if evaluated_balance == streamed_balance {
+                apply_streams(token_account, token_account.last_balance_snapshot, snapshot_time)?
+            } else {
+                evaluate_balance(token_account, config, snapshot_time)?
+            };
+            token_account.checkpoint_time = snapshot_time;
+        }
+    }
+
+    let decayed_amount = realized_decay(streamed_balance, evaluated_balance);
+
+    // Curves only age while the mint is live
+    let decay_start = config.decay_start(token_account.last_update_time, current_time);
+    token_account.curve_age = token_account.curve_age.saturating_add(current_time.saturating_sub(decay_start).max(0));
+
+    // Growth and deposits raise the floor basis, decay leaves it and withdrawals lower it
+    let floor_basis = token_account.floor_basis.max(evaluated_balance);
+    token_account.floor_basis = if new_balance >= evaluated_balance {
+        floor_basis.saturating_add(new_balance - evaluated_balance)
+    } else {
+        floor_basis.saturating_sub(evaluated_balance - new_balance)
+    };
+    token_account.last_balance_snapshot = new_balance;
+    token_account.last_update_time = current_time;
+    token_account.streams.retain(|leg| leg.end_time > current_time);
+
+    decay_pool.amount = decay_pool.amount.checked_add(decayed_amount)
+        .ok_or(TokenError::Overflow)?;
 
-    parser.evaluate()
-        .map(|result| (result * DECIMALS_FACTOR as f64).round() as u64)
-        .map_err(|_| TokenError::BalanceEvaluationError.into())
+    Ok(())
+}
+
+/// Checks that `amount` can leave an account whose evaluated balance is `balance`
+pub fn ensure_spendable(
+    token_account: &TokenAccount,
+    balance: u64,
+    amount: u64,
+    config: &CurveConfig,
+    current_time: i64,
+) -> Result<()> {
+    if balance < amount {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
+    // Only the vested portion can leave the sender
+    let locked = locked_balance(token_account, config.params_for(token_account)?, balance, current_time);
+    if balance - locked < amount {
+        return Err(TokenError::InsufficientVestedFunds.into());
+    }
+    Ok(())
+}
+
+/// Moves `amount` from one account to another, settling both at `current_time`.
+/// Returns the evaluated balances of both sides before the move.
+#[allow(clippy::too_many_arguments)]
+pub fn settle_transfer(
+    from: &mut TokenAccount,
+    from_decay_pool: &mut DecayPool,
+    to: &mut TokenAccount,
+    to_decay_pool: &mut DecayPool,
+    amount: u64,
+    exempt: (bool, bool),
+    config: &CurveConfig,
+    current_time: i64,
+) -> Result<(u64, u64)> {
+    let from_balance = evaluate_balance_unless_exempt(from, exempt.0, config, current_time)?;
+    let to_balance = evaluate_balance_unless_exempt(to, exempt.1, config, current_time)?;
+
+    ensure_spendable(from, from_balance, amount, config, current_time)?;
+
+    let new_from_balance = from_balance.checked_sub(amount)
+        .ok_or(TokenError::Overflow)?;
+    let new_to_balance = to_balance.checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+
+    settle(from, from_decay_pool, from_balance, new_from_balance, config, current_time)?;
+    settle(to, to_decay_pool, to_balance, new_to_balance, config, current_time)?;
+
+    Ok((from_balance, to_balance))
 }
 
 // Helper function to parse amount from string
 pub fn parse_amount(amount_str: &str) -> Result<u64> {
     amount_str.parse::<u64>().map_err(|_| TokenError::InvalidAmount.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{AccountState, PauseType, StreamLeg};
+
+    fn token_account(last_balance_snapshot: u64, last_update_time: i64) -> TokenAccount {
+        TokenAccount {
+            mint: Pubkey::default(),
+            owner: Pubkey::default(),
+            last_balance_snapshot,
+            current_chrono_equation: ChronoEquationType::Exponential,
+            creation_time: 0,
+            last_update_time,
+            state: AccountState::Initialized,
+            delegate: Pubkey::default(),
+            delegated_amount: 0,
+            close_authority: None,
+            last_reup_time: 0,
+            reup_streak: 0,
+            subscription_plan: Pubkey::default(),
+            subscription_expiry: 0,
+            subscription_carried_until: 0,
+            subscription_grace_period: 0,
+            subscription_renewals: 0,
+            subscription_auto_renew: false,
+            cancelled_plan: Pubkey::default(),
+            vesting_total: 0,
+            floor_basis: last_balance_snapshot,
+            checkpoint_time: 0,
+            checkpoint_balance: 0,
+            curve_age: 0,
+            tier: None,
+            streams: Vec::new(),
+        }
+    }
+
+    fn decay_pool(amount: u64) -> DecayPool {
+        DecayPool { token_account: Pubkey::default(), amount, bump: 0 }
+    }
+
+    fn exponential_params() -> EquationParams {
+        EquationParams {
+            decay_constant: Some(0.05),
+            time_unit: Some(86_400),
+            ..Default::default()
+        }
+    }
+
+    fn config(params: &EquationParams) -> CurveConfig<'_> {
+        CurveConfig { params, schedule: None, tiers: None, decay_pauses: &[], snapshot_time: None }
+    }
+
+    /// Deterministic xorshift so failures reproduce
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % bound
+        }
+    }
+
+    #[test]
+    fn settle_credits_realized_decay_to_pool() {
+        let params = exponential_params();
+        let config = config(&params);
+        let mut account = token_account(1_000, 0);
+        let mut pool = decay_pool(5);
+
+        settle(&mut account, &mut pool, 900, 950, &config, 10).unwrap();
+
+        assert_eq!(account.last_balance_snapshot, 950);
+        assert_eq!(account.last_update_time, 10);
+        assert_eq!(pool.amount, 105);
+    }
+
+    #[test]
+    fn settle_realizes_no_decay_on_growth() {
+        let params = exponential_params();
+        let config = config(&params);
+        let mut account = token_account(1_000, 0);
+        let mut pool = decay_pool(0);
+
+        settle(&mut account, &mut pool, 1_200, 1_200, &config, 10).unwrap();
+
+        assert_eq!(pool.amount, 0);
+    }
+
+    #[test]
+    fn floor_holds_across_repeated_settlements() {
+        let params = EquationParams { min_balance_ratio: Some(5_000), ..exponential_params() };
+        let config = config(&params);
+        let mut account = token_account(1_000 * DECIMALS_FACTOR, 0);
+        let mut pool = decay_pool(0);
+
+        for day in 1..=200 {
+            let current_time = day * 86_400;
+            let balance = evaluate_balance(&account, &config, current_time).unwrap();
+            settle(&mut account, &mut pool, balance, balance, &config, current_time).unwrap();
+        }
+
+        assert_eq!(account.last_balance_snapshot, 500 * DECIMALS_FACTOR);
+        assert_eq!(account.floor_basis, 1_000 * DECIMALS_FACTOR);
+    }
+
+    #[test]
+    fn logistic_curve_does_not_restart_on_settlement() {
+        let params = EquationParams {
+            midpoint: Some(30 * 86_400),
+            steepness: Some(0.2),
+            time_unit: Some(86_400),
+            ..Default::default()
+        };
+        let config = config(&params);
+        let mut once = token_account(1_000 * DECIMALS_FACTOR, 0);
+        once.current_chrono_equation = ChronoEquationType::Logistic;
+        let mut split = once.clone();
+        let (mut once_pool, mut split_pool) = (decay_pool(0), decay_pool(0));
+
+        let balance = evaluate_balance(&once, &config, 40 * 86_400).unwrap();
+        settle(&mut once, &mut once_pool, balance, balance, &config, 40 * 86_400).unwrap();
+
+        for current_time in [25 * 86_400, 40 * 86_400] {
+            let balance = evaluate_balance(&split, &config, current_time).unwrap();
+            settle(&mut split, &mut split_pool, balance, balance, &config, current_time).unwrap();
+        }
+
+        let drift = once.last_balance_snapshot.abs_diff(split.last_balance_snapshot);
+        assert!(drift <= 1, "split settlement drifted by {}", drift);
+        assert!(once.last_balance_snapshot < 500 * DECIMALS_FACTOR);
+        assert_eq!(split.curve_age, 40 * 86_400);
+    }
+
+    #[test]
+    fn deflationary_curve_clamps_to_zero_before_the_floor() {
+        let params = EquationParams {
+            decay_rate: Some(1),
+            time_unit: Some(1),
+            min_balance_ratio: Some(1_000),
+            ..Default::default()
+        };
+        let config = config(&params);
+        let mut account = token_account(10 * DECIMALS_FACTOR, 0);
+        account.current_chrono_equation = ChronoEquationType::Deflationary;
+
+        let curve = evaluate_curve(account.last_balance_snapshot, &account.current_chrono_equation, &params, 0, 0, 100).unwrap();
+        assert_eq!(curve, 0);
+        assert_eq!(evaluate_balance(&account, &config, 100).unwrap(), DECIMALS_FACTOR);
+    }
+
+    #[test]
+    fn decay_start_skips_every_pause() {
+        let params = exponential_params();
+        let pauses = [
+            DecayPause { paused_at: 100, resumed_at: Some(200) },
+            DecayPause { paused_at: 300, resumed_at: Some(350) },
+            DecayPause { paused_at: 500, resumed_at: None },
+        ];
+        let config = CurveConfig { decay_pauses: &pauses, ..config(&params) };
+
+        assert_eq!(config.decay_start(0, 1_000), 650);
+        assert_eq!(config.decay_start(150, 400), 250);
+        assert_eq!(config.decay_start(360, 450), 360);
+    }
+
+    #[test]
+    fn settle_checkpoints_the_balance_at_the_snapshot_time() {
+        let params = exponential_params();
+        let config = CurveConfig { snapshot_time: Some(86_400), ..config(&params) };
+        let mut account = token_account(1_000 * DECIMALS_FACTOR, 0);
+        let mut pool = decay_pool(0);
+
+        let at_snapshot = evaluate_balance(&account, &config, 86_400).unwrap();
+        let balance = evaluate_balance(&account, &config, 2 * 86_400).unwrap();
+        settle(&mut account, &mut pool, balance, 0, &config, 2 * 86_400).unwrap();
+        settle(&mut account, &mut pool, 0, 5, &config, 3 * 86_400).unwrap();
+
+        assert_eq!(account.checkpoint_time, 86_400);
+        assert_eq!(account.checkpoint_balance, at_snapshot);
+    }
+
+    #[test]
+    fn transfer_settlement_conserves_snapshots_plus_pools() {
+        let params = exponential_params();
+        let config = config(&params);
+        let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0..500 {
+            let mut from = token_account(rng.next(1_000 * DECIMALS_FACTOR), 0);
+            let mut to = token_account(rng.next(1_000 * DECIMALS_FACTOR), 0);
+            let mut from_pool = decay_pool(rng.next(DECIMALS_FACTOR));
+            let mut to_pool = decay_pool(rng.next(DECIMALS_FACTOR));
+
+            // Any decayed balance at or below each snapshot
+            let from_balance = rng.next(from.last_balance_snapshot + 1);
+            let to_balance = rng.next(to.last_balance_snapshot + 1);
+            let amount = rng.next(from_balance + 1);
+            let before = from.last_balance_snapshot + from_pool.amount + to.last_balance_snapshot + to_pool.amount;
+            let from_pool_credit = from_pool.amount + from.last_balance_snapshot - from_balance;
+            let to_pool_credit = to_pool.amount + to.last_balance_snapshot - to_balance;
+
+            settle(&mut from, &mut from_pool, from_balance, from_balance - amount, &config, 10).unwrap();
+            settle(&mut to, &mut to_pool, to_balance, to_balance + amount, &config, 10).unwrap();
+
+            let after = from.last_balance_snapshot + from_pool.amount + to.last_balance_snapshot + to_pool.amount;
+            assert_eq!(after, before);
+            assert_eq!(from_pool.amount, from_pool_credit);
+            assert_eq!(to_pool.amount, to_pool_credit);
+        }
+    }
+
+    #[test]
+    fn settle_conserves_snapshot_plus_pool() {
+        let params = exponential_params();
+        let config = config(&params);
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0..500 {
+            let snapshot = rng.next(1_000 * DECIMALS_FACTOR);
+            let mut account = token_account(snapshot, rng.next(1_000) as i64);
+            let mut pool = decay_pool(rng.next(DECIMALS_FACTOR));
+            let current_time = account.last_update_time + rng.next(365 * 86_400) as i64;
+
+            let before = account.last_balance_snapshot + pool.amount;
+            let balance = evaluate_balance(&account, &config, current_time).unwrap();
+            settle(&mut account, &mut pool, balance, balance, &config, current_time).unwrap();
+
+            assert!(balance <= snapshot);
+            assert_eq!(account.last_balance_snapshot + pool.amount, before);
+        }
+    }
+
+    #[test]
+    fn settle_transfer_conserves_snapshots_plus_pools() {
+        let params = exponential_params();
+        let config = config(&params);
+        let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0..500 {
+            let mut from = token_account(rng.next(1_000 * DECIMALS_FACTOR), rng.next(1_000) as i64);
+            let mut to = token_account(rng.next(1_000 * DECIMALS_FACTOR), rng.next(1_000) as i64);
+            let mut from_pool = decay_pool(rng.next(DECIMALS_FACTOR));
+            let mut to_pool = decay_pool(rng.next(DECIMALS_FACTOR));
+            let current_time = 1_000 + rng.next(365 * 86_400) as i64;
+            let exempt = (rng.next(2) == 1, rng.next(2) == 1);
+
+            let from_balance = evaluate_balance_unless_exempt(&from, exempt.0, &config, current_time).unwrap();
+            let amount = rng.next(from_balance + 1);
+            let before = from.last_balance_snapshot + from_pool.amount + to.last_balance_snapshot + to_pool.amount;
+
+            settle_transfer(
+                &mut from,
+                &mut from_pool,
+                &mut to,
+                &mut to_pool,
+                amount,
+                exempt,
+                &config,
+                current_time
+            ).unwrap();
+
+            let after = from.last_balance_snapshot + from_pool.amount + to.last_balance_snapshot + to_pool.amount;
+            assert_eq!(after, before);
+            assert_eq!(from.last_update_time, current_time);
+            assert_eq!(to.last_update_time, current_time);
+        }
+    }
+
+    #[test]
+    fn streams_move_balance_from_sender_to_recipient() {
+        let params = exponential_params();
+        let config = config(&params);
+        let leg = StreamLeg {
+            stream: Pubkey::new_unique(),
+            rate_per_unit: DECIMALS_FACTOR,
+            start_time: 0,
+            end_time: 100,
+            outgoing: true,
+        };
+        let mut sender = token_account(1_000 * DECIMALS_FACTOR, 0);
+        sender.streams.push(leg);
+        let mut recipient = token_account(0, 0);
+        recipient.streams.push(StreamLeg { outgoing: false, ..leg });
+        let (mut sender_pool, mut recipient_pool) = (decay_pool(0), decay_pool(0));
+
+        // Halfway through, the streamed half has moved and the unstreamed half is locked
+        let sender_balance = evaluate_balance(&sender, &config, 50).unwrap();
+        let recipient_balance = evaluate_balance(&recipient, &config, 50).unwrap();
+        assert_eq!(recipient_balance, 50 * DECIMALS_FACTOR);
+        assert!(sender_balance < 950 * DECIMALS_FACTOR && sender_balance > 949 * DECIMALS_FACTOR);
+        assert_eq!(locked_balance(&sender, &params, sender_balance, 50), 50 * DECIMALS_FACTOR);
+        assert!(ensure_spendable(&sender, sender_balance, sender_balance - 49 * DECIMALS_FACTOR, &config, 50).is_err());
+        assert_eq!(evaluate_balance_unless_exempt(&recipient, true, &config, 50).unwrap(), 50 * DECIMALS_FACTOR);
+
+        // Only the curve's decay reaches the pools, whenever each side settles
+        settle(&mut sender, &mut sender_pool, sender_balance, sender_balance, &config, 50).unwrap();
+        for current_time in [80, 200] {
+            let balance = evaluate_balance(&recipient, &config, current_time).unwrap();
+            settle(&mut recipient, &mut recipient_pool, balance, balance, &config, current_time).unwrap();
+        }
+        let sender_balance = evaluate_balance(&sender, &config, 200).unwrap();
+        settle(&mut sender, &mut sender_pool, sender_balance, sender_balance, &config, 200).unwrap();
+
+        let total = sender.last_balance_snapshot + sender_pool.amount + recipient.last_balance_snapshot + recipient_pool.amount;
+        assert_eq!(total, 1_000 * DECIMALS_FACTOR);
+        assert!(recipient.last_balance_snapshot > 99 * DECIMALS_FACTOR);
+        assert!(sender.streams.is_empty() && recipient.streams.is_empty());
+    }
+
+    #[test]
+    fn settle_transfer_rejects_overspend_without_settling() {
+        let params = exponential_params();
+        let config = config(&params);
+        let mut from = token_account(100, 0);
+        let mut to = token_account(0, 0);
+        let (mut from_pool, mut to_pool) = (decay_pool(0), decay_pool(0));
+
+        let result = settle_transfer(&mut from, &mut from_pool, &mut to, &mut to_pool, 101, (true, false), &config, 10);
+
+        assert!(result.is_err());
+        assert_eq!(from.last_balance_snapshot, 100);
+        assert_eq!(from.last_update_time, 0);
+    }
+    #[test]
+    fn vault_holds_inbound_transfers_flat_until_unstaked() {
+        let params = exponential_params();
+        let config = config(&params);
+        let extension = ChronoExtension::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            ChronoEquationType::Exponential,
+            PauseType::ReUp,
+            exponential_params(),
+            0,
+            0,
+            None
+        );
+
+        // A stake vault holding 1,000 staked tokens
+        let mut vault = token_account(1_000, 0);
+        vault.state = AccountState::Vault;
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        vault.try_serialize(&mut data).unwrap();
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &crate::ID, false, 0);
+        let mut vault = Account::<TokenAccount>::try_from(&info).unwrap();
+        let mut vault_pool = decay_pool(0);
+
+        // Anyone can transfer into the vault, it must not start decaying
+        let mut from = token_account(500, 0);
+        let mut from_pool = decay_pool(0);
+        let vault_exempt = is_exempt(&extension, None, &vault, None).unwrap();
+        assert!(vault_exempt);
+        settle_transfer(&mut from, &mut from_pool, &mut vault, &mut vault_pool, 500, (true, vault_exempt), &config, 86_400)
+            .unwrap();
+
+        // Unstaking every staked token still fits in the vault
+        let current_time = 30 * 86_400;
+        let vault_exempt = is_exempt(&extension, None, &vault, None).unwrap();
+        let balance = evaluate_balance_unless_exempt(&vault, vault_exempt, &config, current_time).unwrap();
+        assert_eq!(balance.checked_sub(1_000), Some(500));
+        assert_eq!(vault_pool.amount, 0);
+    }
 }
\ No newline at end of file
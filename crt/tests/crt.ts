@@ -10,7 +10,9 @@ type EquationType =
   | { inflationary: {} }
   | { deflationary: {} }
   | { linear: {} }
-  | { exponential: {} };
+  | { exponential: {} }
+  | { vesting: {} }
+  | { logistic: {} };
 
 type PauseType = 
   | { pause: {} }
@@ -27,6 +29,74 @@ type EquationParams = {
   slope: anchor.BN;
   decayConstant: number;
   reupBoost: anchor.BN;
+  cliffTime: anchor.BN;
+  vestingStart: anchor.BN;
+  vestingDuration: anchor.BN;
+  halfLifeSeconds: anchor.BN | null;
+  midpoint: anchor.BN | null;
+  steepness: number | null;
+  minBalanceRatio: number | null;
+  maxBalance: anchor.BN | null;
+};
+
+type TimeBase =
+  | { unixTimestamp: {} }
+  | { slot: {} }
+  | { epoch: {} };
+
+type ExemptionKind =
+  | { tokenAccount: {} }
+  | { ownerProgram: {} };
+
+type TransferFeeConfig = {
+  feeBps: number;
+  maxFee: anchor.BN;
+  destination: { senderDecayPool: {} } | { feeVault: {} };
+  feeAuthority: PublicKey;
+};
+
+type EmissionSchedule = {
+  start: anchor.BN;
+  epochLength: anchor.BN;
+  curve:
+    | { constant: { amountPerEpoch: anchor.BN } }
+    | { halving: { initialPerEpoch: anchor.BN; halvingInterval: anchor.BN } };
+  treasury: PublicKey;
+};
+
+type EscrowDecayMode =
+  | { exempt: {} }
+  | { shared: {} };
+
+type RefundPolicy =
+  | { none: {} }
+  | { proRata: {} }
+  | { proRataMinusFee: { feeBps: number } };
+
+type SchedulePhase = {
+  startOffset: anchor.BN;
+  equationType: EquationType;
+  params: EquationParams;
+};
+
+type Tier = {
+  name: string;
+  equationType: EquationType;
+  params: EquationParams;
+};
+
+type StreamLeg = {
+  stream: PublicKey;
+  ratePerUnit: anchor.BN;
+  startTime: anchor.BN;
+  endTime: anchor.BN;
+  outgoing: boolean;
+};
+
+type ReUpPolicy = {
+  minInterval: anchor.BN;
+  maxInterval: anchor.BN;
+  percentageSchedule: number[];
 };
 
 ///this the typescript version of the eqaution calculation should ultimately be in a different file
@@ -36,10 +106,27 @@ interface TokenAccountData {
   lastBalanceSnapshot: anchor.BN;
   currentChronoEquation: EquationType;
   creationTime: anchor.BN;
-  state: { initialized: {} } | { frozen: {} } | { revoked: {} };
+  state: { initialized: {} } | { frozen: {} } | { revoked: {} } | { vault: {} };
   delegate: PublicKey;
   delegatedAmount: anchor.BN;
   closeAuthority: PublicKey | null;
+  lastUpdateTime: anchor.BN;
+  lastReupTime: anchor.BN;
+  reupStreak: number;
+  subscriptionPlan: PublicKey;
+  subscriptionExpiry: anchor.BN;
+  subscriptionCarriedUntil: anchor.BN;
+  subscriptionGracePeriod: anchor.BN;
+  subscriptionRenewals: number;
+  subscriptionAutoRenew: boolean;
+  cancelledPlan: PublicKey;
+  vestingTotal: anchor.BN;
+  floorBasis: anchor.BN;
+  checkpointTime: anchor.BN;
+  checkpointBalance: anchor.BN;
+  curveAge: anchor.BN;
+  tier: number | null;
+  streams: StreamLeg[];
 }
 
 interface ChronoExtension {
@@ -49,6 +136,15 @@ interface ChronoExtension {
   pauseType: PauseType;
   equationParams: EquationParams;
   reupPercentage: number;
+  bump: number;
+  reupPolicy: ReUpPolicy | null;
+  hasSchedule: boolean;
+  timeBase: TimeBase;
+  hasExemptionRegistry: boolean;
+  transferFee: TransferFeeConfig | null;
+  decayPauses: { pausedAt: anchor.BN; resumedAt: anchor.BN | null }[];
+  snapshotTime: anchor.BN | null;
+  snapshotDeadline: anchor.BN;
 }
 
 export class ChronoTokenSDK {
@@ -61,11 +157,101 @@ export class ChronoTokenSDK {
   }
 
   
+  findChronoExtension(mint: PublicKey): PublicKey {
+    const [chronoExtension] = PublicKey.findProgramAddressSync(
+      [Buffer.from('chrono_extension'), mint.toBuffer()],
+      this.program.programId
+    );
+    return chronoExtension;
+  }
+
+  findEquationSchedule(mint: PublicKey): PublicKey {
+    const [schedule] = PublicKey.findProgramAddressSync(
+      [Buffer.from('equation_schedule'), mint.toBuffer()],
+      this.program.programId
+    );
+    return schedule;
+  }
+
+  // The schedule account is only passed for mints that have one
+  async resolveSchedule(mint: PublicKey): Promise<PublicKey | null> {
+    const chronoExtension = await this.program.account.chronoExtension.fetch(this.findChronoExtension(mint));
+    return chronoExtension.hasSchedule ? this.findEquationSchedule(mint) : null;
+  }
+
+  findTierTable(mint: PublicKey): PublicKey {
+    const [tierTable] = PublicKey.findProgramAddressSync(
+      [Buffer.from('tier_table'), mint.toBuffer()],
+      this.program.programId
+    );
+    return tierTable;
+  }
+
+  findExemptionRegistry(mint: PublicKey): PublicKey {
+    const [exemptionRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from('exemption_registry'), mint.toBuffer()],
+      this.program.programId
+    );
+    return exemptionRegistry;
+  }
+
+  // The exemption registry account is only passed for mints that have one
+  async resolveExemptionRegistry(mint: PublicKey): Promise<PublicKey | null> {
+    const chronoExtension = await this.program.account.chronoExtension.fetch(this.findChronoExtension(mint));
+    return chronoExtension.hasExemptionRegistry ? this.findExemptionRegistry(mint) : null;
+  }
+
+  findFeeVault(mint: PublicKey): PublicKey {
+    const [feeVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from('fee_vault'), mint.toBuffer()],
+      this.program.programId
+    );
+    return feeVault;
+  }
+
+  // The fee vault account is only passed for mints that withhold fees into it
+  async resolveFeeVault(mint: PublicKey): Promise<PublicKey | null> {
+    const chronoExtension = await this.program.account.chronoExtension.fetch(this.findChronoExtension(mint));
+    return chronoExtension.transferFee && 'feeVault' in chronoExtension.transferFee.destination
+      ? this.findFeeVault(mint)
+      : null;
+  }
+
+  // Owner of a token account, read by the program for owner program exemptions
+  async findTokenAccountOwner(tokenAccount: PublicKey): Promise<PublicKey> {
+    const { owner } = await this.program.account.tokenAccount.fetch(tokenAccount);
+    return owner;
+  }
+
+  // One writable (token account, decay pool, owner) triple per token account
+  async settlementAccounts(tokenAccounts: PublicKey[]): Promise<anchor.web3.AccountMeta[]> {
+    const triples = await Promise.all(tokenAccounts.map(async (tokenAccount) => {
+      const [decayPool] = PublicKey.findProgramAddressSync(
+        [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+        this.program.programId
+      );
+      return [
+        { pubkey: tokenAccount, isWritable: true, isSigner: false },
+        { pubkey: decayPool, isWritable: true, isSigner: false },
+        { pubkey: await this.findTokenAccountOwner(tokenAccount), isWritable: false, isSigner: false },
+      ];
+    }));
+    return triples.flat();
+  }
+
+  // The tier table account is only passed for mints that have one
+  async resolveTierTable(mint: PublicKey): Promise<PublicKey | null> {
+    const tierTable = this.findTierTable(mint);
+    const info = await this.provider.connection.getAccountInfo(tierTable);
+    return info ? tierTable : null;
+  }
+
   private calculateBalance(
     lastBalanceSnapshot: anchor.BN,
     equation: EquationType,
     params: EquationParams,
-    currentTime: anchor.BN
+    currentTime: anchor.BN,
+    curveAge: anchor.BN = new anchor.BN(0)
   ): anchor.BN {
     const elapsedTime = currentTime.sub(params.snapshotTime);
 
@@ -83,11 +269,43 @@ export class ChronoTokenSDK {
       // Note: This is a simplified calculation and may not be precise for large numbers
       const decayFactor = Math.exp(-params.decayConstant * elapsedTime.toNumber() / params.timeUnit.toNumber());
       return lastBalanceSnapshot.muln(decayFactor);
+    } else if ('logistic' in equation) {
+      const timeUnit = params.timeUnit.toNumber();
+      const age = curveAge.toNumber();
+      const start = 1 + Math.exp(params.steepness * (age - params.midpoint.toNumber()) / timeUnit);
+      const now = 1 + Math.exp(params.steepness * (age + elapsedTime.toNumber() - params.midpoint.toNumber()) / timeUnit);
+      return new anchor.BN(Math.round(lastBalanceSnapshot.toNumber() * start / now));
+    } else if ('vesting' in equation) {
+      // Vesting only locks part of the balance, see getBalance for the split
+      return lastBalanceSnapshot;
     } else {
       throw new Error('Unknown equation type');
     }
   }
 
+  // Mirrors EquationParams::bound, the ceiling only stops growth
+  private boundBalance(
+    lastBalanceSnapshot: anchor.BN,
+    evaluatedBalance: anchor.BN,
+    params: EquationParams
+  ): anchor.BN {
+    let balance = evaluatedBalance;
+    if (params.maxBalance) {
+      balance = anchor.BN.min(balance, anchor.BN.max(params.maxBalance, lastBalanceSnapshot));
+    }
+    if (params.minBalanceRatio !== null) {
+      balance = anchor.BN.max(balance, lastBalanceSnapshot.muln(params.minBalanceRatio).divn(10_000));
+    }
+    return balance;
+  }
+
+  // Mirrors StreamLeg::streamed_between
+  private streamedBetween(leg: StreamLeg, from: anchor.BN, to: anchor.BN): anchor.BN {
+    const start = anchor.BN.max(from, leg.startTime);
+    const end = anchor.BN.min(to, leg.endTime);
+    return end.lte(start) ? new anchor.BN(0) : end.sub(start).mul(leg.ratePerUnit);
+  }
+
   ///To do: update initMint to use extensions account and in turn, create get balance with an account structure rather than data
   
    async initializeMint(
@@ -101,7 +319,13 @@ export class ChronoTokenSDK {
     equationType: EquationType | null,
     pauseType: PauseType | null,
     equationParams: EquationParams | null,
-    reupPercentage: number | null
+    reupPercentage: number | null,
+    reupPolicy: ReUpPolicy | null,
+    timeBase: TimeBase | null = null,
+    transferFee: TransferFeeConfig | null = null,
+    maxSupply: anchor.BN | null = null,
+    emission: EmissionSchedule | null = null,
+    pauseAuthority: PublicKey | null = null
   ): Promise<string> {
     
     const [ chronoExtensionAccount ] = PublicKey.findProgramAddressSync(
@@ -122,13 +346,18 @@ export class ChronoTokenSDK {
         decimals,
         supply,
         freezeAuthority,
-        0, // bump (you might want to calculate this)
         enableChronoHook,
         chronoHookProgramId,
         equationType,
         pauseType,
         equationParams,
-        reupPercentage
+        reupPercentage,
+        reupPolicy,
+        timeBase,
+        transferFee,
+        maxSupply,
+        emission,
+        pauseAuthority
       )
       .accounts(accounts)
       .signers([authority])
@@ -157,6 +386,7 @@ export class ChronoTokenSDK {
       tokenAccount: tokenAccount,
       decayPool: decayPool,
       mint: mint,
+      chronoExtension: this.findChronoExtension(mint),
       authority: authority.publicKey,
       payer: payer,
       systemProgram: SystemProgram.programId,
@@ -176,14 +406,8 @@ export class ChronoTokenSDK {
   }
 
 
-  async transfer(
-      mint: PublicKey,
-      fromPubkey: PublicKey,
-      toPubkey: PublicKey,
-      amount: anchor.BN
-  ): Promise<string> {
-    
-
+  // Accounts shared by transfer and transferChecked
+  private async transferAccounts(mint: PublicKey, fromPubkey: PublicKey, toPubkey: PublicKey) {
     const [fromDecayPool] = PublicKey.findProgramAddressSync(
         [Buffer.from("decay_pool"), fromPubkey.toBuffer()],
         this.program.programId
@@ -193,15 +417,31 @@ export class ChronoTokenSDK {
         this.program.programId
     );
 
-    //Declared the accounts object separately because object literal had a type issue
-    let accounts = {
+    return {
       mint,
+      chronoExtension: this.findChronoExtension(mint),
+      schedule: await this.resolveSchedule(mint),
+      tierTable: await this.resolveTierTable(mint),
       from: fromPubkey,
       to: toPubkey,
       fromDecayPool,
       toDecayPool,
+      feeVault: await this.resolveFeeVault(mint),
+      exemptionRegistry: await this.resolveExemptionRegistry(mint),
+      fromOwner: await this.findTokenAccountOwner(fromPubkey),
+      toOwner: await this.findTokenAccountOwner(toPubkey),
       authority: this.provider.wallet.publicKey,
-    }
+    };
+  }
+
+  async transfer(
+      mint: PublicKey,
+      fromPubkey: PublicKey,
+      toPubkey: PublicKey,
+      amount: anchor.BN
+  ): Promise<string> {
+    //Declared the accounts object separately because object literal had a type issue
+    let accounts = await this.transferAccounts(mint, fromPubkey, toPubkey);
 
     const tx = await this.program.methods
         .transfer(amount)
@@ -211,18 +451,77 @@ export class ChronoTokenSDK {
     return tx;
   }
 
+  async transferChecked(
+      mint: PublicKey,
+      fromPubkey: PublicKey,
+      toPubkey: PublicKey,
+      amount: anchor.BN,
+      decimals: number
+  ): Promise<string> {
+    let accounts = await this.transferAccounts(mint, fromPubkey, toPubkey);
+
+    const tx = await this.program.methods
+        .transferChecked(amount, decimals)
+        .accounts(accounts)
+        .rpc();
+
+    return tx;
+  }
+
+  async batchTransfer(
+      mint: PublicKey,
+      fromPubkey: PublicKey,
+      recipients: { tokenAccount: PublicKey; amount: anchor.BN }[]
+  ): Promise<string> {
+    const [fromDecayPool] = PublicKey.findProgramAddressSync(
+        [Buffer.from("decay_pool"), fromPubkey.toBuffer()],
+        this.program.programId
+    );
+
+    // One (token account, decay pool, owner) triple per amount, in order
+    const remainingAccounts = await this.settlementAccounts(recipients.map(({ tokenAccount }) => tokenAccount));
+
+    const tx = await this.program.methods
+        .batchTransfer(recipients.map(({ amount }) => amount))
+        .accounts({
+          mint,
+          chronoExtension: this.findChronoExtension(mint),
+          schedule: await this.resolveSchedule(mint),
+          tierTable: await this.resolveTierTable(mint),
+          exemptionRegistry: await this.resolveExemptionRegistry(mint),
+          from: fromPubkey,
+          fromDecayPool,
+          feeVault: await this.resolveFeeVault(mint),
+          fromOwner: await this.findTokenAccountOwner(fromPubkey),
+          authority: this.provider.wallet.publicKey,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+
+    return tx;
+  }
+
   async mintTo(
     mint: PublicKey,
     tokenAccount: PublicKey,
     amount: anchor.BN
   ): Promise<string> {
-
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
 
     const tx = await this.program.methods
       .mintTo(amount)
       .accounts({
         mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
         tokenAccount: tokenAccount,
+        decayPool: decayPool,
+        owner: await this.findTokenAccountOwner(tokenAccount),
         authority: this.provider.wallet.publicKey,
       })
       .rpc();
@@ -236,10 +535,19 @@ export class ChronoTokenSDK {
     decayPool: PublicKey,
     chronoHookProgram: PublicKey
   ): Promise<string> {
+    const [chronoExtension] = PublicKey.findProgramAddressSync(
+      [Buffer.from('chrono_extension'), mint.toBuffer()],
+      this.program.programId
+    );
+
     const tx = await this.program.methods
       .reup()
       .accounts({
         mint: mint,
+        chronoExtension: chronoExtension,
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
         tokenAccount: tokenAccount,
         decayPool: decayPool,
         authority: this.provider.wallet.publicKey,
@@ -274,13 +582,22 @@ export class ChronoTokenSDK {
     tokenAccount: PublicKey,
     amount: anchor.BN
   ): Promise<string> {
-
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
 
     const tx = await this.program.methods
       .burn(amount)
       .accounts({
         mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
         tokenAccount: tokenAccount,
+        decayPool: decayPool,
+        owner: await this.findTokenAccountOwner(tokenAccount),
         authority: this.provider.wallet.publicKey,
       })
       .rpc();
@@ -288,40 +605,1163 @@ export class ChronoTokenSDK {
     return tx;
   }
 
-  ///get balance
-  async getTokenAccountBalance(tokenAccountAddress: PublicKey): Promise<anchor.BN> {
-    const tokenAccount = await this.program.account.tokenAccount.fetch(tokenAccountAddress);
-    const currentTime = new anchor.BN(Math.floor(Date.now() / 1000));
+  async migrateDecayPool(tokenAccount: PublicKey): Promise<string> {
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
 
-    // Fetch the mint account
-    const mint = await this.program.account.mint.fetch(tokenAccount.mint);
+    const tx = await this.program.methods
+      .migrateDecayPool()
+      .accounts({
+        tokenAccount: tokenAccount,
+        decayPool: decayPool,
+      })
+      .rpc();
 
-    // Get the ChronoExtension PDA
-    const [chronoExtensionPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from('chrono_extension'), tokenAccount.mint.toBuffer()],
+    return tx;
+  }
+
+  async migrateChronoExtension(mint: PublicKey): Promise<string> {
+    const [chronoExtension] = PublicKey.findProgramAddressSync(
+      [Buffer.from("chrono_extension"), mint.toBuffer()],
       this.program.programId
     );
 
-    // Fetch the ChronoExtension account
-    let chronoExtension: ChronoExtension | null = null;
-    try {
-      chronoExtension = await this.program.account.chronoExtension.fetch(chronoExtensionPDA);
-    } catch (error) {
-      console.error('Failed to fetch ChronoExtension:', error);
-      // If there's no chrono extension, return the last balance snapshot
-      return tokenAccount.lastBalanceSnapshot;
-    }
+    const tx = await this.program.methods
+      .migrateChronoExtension()
+      .accounts({
+        mint: mint,
+        chronoExtension: chronoExtension,
+        authority: this.provider.wallet.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
 
-    if (!chronoExtension) {
-      // If there's no chrono extension, return the last balance snapshot
-      return tokenAccount.lastBalanceSnapshot;
-    }
+    return tx;
+  }
 
-    return this.calculateBalance(
-      tokenAccount.lastBalanceSnapshot,
-      tokenAccount.currentChronoEquation,
-      chronoExtension.equationParams,
-      currentTime
+  findSubscriptionPlan(mint: PublicKey, merchant: PublicKey, planId: anchor.BN): PublicKey {
+    const [plan] = PublicKey.findProgramAddressSync(
+      [Buffer.from("subscription_plan"), mint.toBuffer(), merchant.toBuffer(), planId.toArrayLike(Buffer, "le", 8)],
+      this.program.programId
+    );
+    return plan;
+  }
+
+  findSubscriptionEscrow(plan: PublicKey): PublicKey {
+    const [escrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("subscription_escrow"), plan.toBuffer()],
+      this.program.programId
+    );
+    return escrow;
+  }
+
+  async createSubscriptionPlan(
+    mint: PublicKey,
+    merchantTokenAccount: PublicKey,
+    merchant: Keypair,
+    planId: anchor.BN,
+    price: anchor.BN,
+    period: anchor.BN,
+    gracePeriod: anchor.BN,
+    maxRenewals: number,
+    refundPolicy: RefundPolicy
+  ): Promise<{ plan: PublicKey; escrow: PublicKey; txSignature: string }> {
+    const plan = this.findSubscriptionPlan(mint, merchant.publicKey, planId);
+    const escrow = this.findSubscriptionEscrow(plan);
+    const [escrowDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), escrow.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .createSubscriptionPlan(planId, price, period, gracePeriod, maxRenewals, refundPolicy)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        plan: plan,
+        merchantTokenAccount: merchantTokenAccount,
+        escrow: escrow,
+        escrowDecayPool: escrowDecayPool,
+        merchant: merchant.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([merchant])
+      .rpc();
+
+    return { plan: plan, escrow: escrow, txSignature: tx };
+  }
+
+  async renewSubscription(
+    mint: PublicKey,
+    plan: PublicKey,
+    subscriber: PublicKey,
+    merchantTokenAccount: PublicKey
+  ): Promise<string> {
+    const [subscriberDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), subscriber.toBuffer()],
+      this.program.programId
     );
+    const [merchantDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), merchantTokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .renewSubscription()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        plan: plan,
+        subscriber: subscriber,
+        subscriberDecayPool: subscriberDecayPool,
+        merchantTokenAccount: merchantTokenAccount,
+        merchantDecayPool: merchantDecayPool,
+        feeVault: await this.resolveFeeVault(mint),
+        merchantOwner: await this.findTokenAccountOwner(merchantTokenAccount),
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async subscribe(plan: PublicKey, tokenAccount: PublicKey): Promise<string> {
+    const { mint } = await this.program.account.subscriptionPlan.fetch(plan);
+    const tx = await this.program.methods
+      .subscribe()
+      .accounts({
+        plan: plan,
+        chronoExtension: this.findChronoExtension(mint),
+        tokenAccount: tokenAccount,
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async unsubscribe(tokenAccount: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .unsubscribe()
+      .accounts({
+        tokenAccount: tokenAccount,
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async collectSubscription(
+    mint: PublicKey,
+    plan: PublicKey,
+    subscriber: PublicKey,
+    merchantTokenAccount: PublicKey,
+    merchant: Keypair
+  ): Promise<string> {
+    const [subscriberDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), subscriber.toBuffer()],
+      this.program.programId
+    );
+    const [merchantDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), merchantTokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .collectSubscription()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        plan: plan,
+        subscriber: subscriber,
+        subscriberDecayPool: subscriberDecayPool,
+        merchantTokenAccount: merchantTokenAccount,
+        merchantDecayPool: merchantDecayPool,
+        feeVault: await this.resolveFeeVault(mint),
+        subscriberOwner: await this.findTokenAccountOwner(subscriber),
+        merchant: merchant.publicKey,
+      })
+      .signers([merchant])
+      .rpc();
+
+    return tx;
+  }
+
+  async cancelSubscription(
+    mint: PublicKey,
+    plan: PublicKey,
+    subscriber: PublicKey
+  ): Promise<string> {
+    const escrow = this.findSubscriptionEscrow(plan);
+    const [subscriberDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), subscriber.toBuffer()],
+      this.program.programId
+    );
+    const [escrowDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), escrow.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .cancelSubscription()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        plan: plan,
+        subscriber: subscriber,
+        subscriberDecayPool: subscriberDecayPool,
+        escrow: escrow,
+        escrowDecayPool: escrowDecayPool,
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async withdrawSubscriptionEscrow(
+    mint: PublicKey,
+    plan: PublicKey,
+    merchantTokenAccount: PublicKey,
+    merchant: Keypair,
+    amount: anchor.BN
+  ): Promise<string> {
+    const escrow = this.findSubscriptionEscrow(plan);
+    const [escrowDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), escrow.toBuffer()],
+      this.program.programId
+    );
+    const [merchantDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), merchantTokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .withdrawSubscriptionEscrow(amount)
+      .accounts({
+        plan: plan,
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        escrow: escrow,
+        escrowDecayPool: escrowDecayPool,
+        merchantTokenAccount: merchantTokenAccount,
+        merchantDecayPool: merchantDecayPool,
+        feeVault: await this.resolveFeeVault(mint),
+        merchant: merchant.publicKey,
+      })
+      .signers([merchant])
+      .rpc();
+
+    return tx;
+  }
+
+  async initializeSchedule(
+    mint: PublicKey,
+    phases: SchedulePhase[]
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .initializeSchedule(phases)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: this.findEquationSchedule(mint),
+        authority: this.provider.wallet.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async initializeTierTable(
+    mint: PublicKey,
+    tiers: Tier[]
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .initializeTierTable(tiers)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        tierTable: this.findTierTable(mint),
+        authority: this.provider.wallet.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async setAccountEquation(
+    mint: PublicKey,
+    tokenAccount: PublicKey,
+    tier: number | null
+  ): Promise<string> {
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .setAccountEquation(tier)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        tokenAccount: tokenAccount,
+        decayPool: decayPool,
+        owner: await this.findTokenAccountOwner(tokenAccount),
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async initializeExemptionRegistry(mint: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .initializeExemptionRegistry()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        exemptionRegistry: this.findExemptionRegistry(mint),
+        authority: this.provider.wallet.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  // Accounts the change affects are settled first, a token account exemption settles `key` itself
+  async addExemption(
+    mint: PublicKey,
+    kind: ExemptionKind,
+    key: PublicKey,
+    affected: PublicKey[] = 'tokenAccount' in kind ? [key] : []
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .addExemption(kind, key)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: this.findExemptionRegistry(mint),
+        authority: this.provider.wallet.publicKey,
+      })
+      .remainingAccounts(await this.settlementAccounts(affected))
+      .rpc();
+
+    return tx;
+  }
+
+  // Accounts the change affects are settled first, a token account exemption settles `key` itself
+  async removeExemption(
+    mint: PublicKey,
+    kind: ExemptionKind,
+    key: PublicKey,
+    affected: PublicKey[] = 'tokenAccount' in kind ? [key] : []
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .removeExemption(kind, key)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: this.findExemptionRegistry(mint),
+        authority: this.provider.wallet.publicKey,
+      })
+      .remainingAccounts(await this.settlementAccounts(affected))
+      .rpc();
+
+    return tx;
+  }
+
+  async initializeFeeVault(mint: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .initializeFeeVault()
+      .accounts({
+        mint: mint,
+        feeVault: this.findFeeVault(mint),
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async withdrawWithheldFees(
+    mint: PublicKey,
+    destination: PublicKey,
+    feeAuthority: Keypair,
+    amount: anchor.BN
+  ): Promise<string> {
+    const [destinationDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), destination.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .withdrawWithheldFees(amount)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        feeVault: this.findFeeVault(mint),
+        destination: destination,
+        destinationDecayPool: destinationDecayPool,
+        destinationOwner: await this.findTokenAccountOwner(destination),
+        feeAuthority: feeAuthority.publicKey,
+      })
+      .signers([feeAuthority])
+      .rpc();
+
+    return tx;
+  }
+
+  async emitScheduled(mint: PublicKey): Promise<string> {
+    const { emission } = await this.program.account.mint.fetch(mint);
+    if (!emission) {
+      throw new Error('Mint has no emission schedule');
+    }
+
+    const tx = await this.program.methods
+      .emitScheduled()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        tokenAccount: emission.treasury,
+        decayPool: PublicKey.findProgramAddressSync(
+          [Buffer.from("decay_pool"), emission.treasury.toBuffer()],
+          this.program.programId
+        )[0],
+        owner: await this.findTokenAccountOwner(emission.treasury),
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async pauseMint(mint: PublicKey, pauseAuthority: Keypair, stopDecay: boolean): Promise<string> {
+    const tx = await this.program.methods
+      .pauseMint(stopDecay)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        authority: pauseAuthority.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([pauseAuthority])
+      .rpc();
+
+    return tx;
+  }
+
+  async resumeMint(mint: PublicKey, pauseAuthority: Keypair): Promise<string> {
+    const tx = await this.program.methods
+      .resumeMint()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        authority: pauseAuthority.publicKey,
+      })
+      .signers([pauseAuthority])
+      .rpc();
+
+    return tx;
+  }
+
+  findDistributor(mint: PublicKey, distributorId: anchor.BN): PublicKey {
+    const [distributor] = PublicKey.findProgramAddressSync(
+      [Buffer.from('distributor'), mint.toBuffer(), distributorId.toArrayLike(Buffer, 'le', 8)],
+      this.program.programId
+    );
+    return distributor;
+  }
+
+  findClaimStatus(distributor: PublicKey, index: anchor.BN): PublicKey {
+    const [claimStatus] = PublicKey.findProgramAddressSync(
+      [Buffer.from('claim_status'), distributor.toBuffer(), index.toArrayLike(Buffer, 'le', 8)],
+      this.program.programId
+    );
+    return claimStatus;
+  }
+
+  async createDistributor(
+    mint: PublicKey,
+    distributorId: anchor.BN,
+    merkleRoot: number[],
+    total: anchor.BN,
+    claimDeadline: anchor.BN
+  ): Promise<{ distributor: PublicKey; txSignature: string }> {
+    const distributor = this.findDistributor(mint, distributorId);
+
+    const txSignature = await this.program.methods
+      .createDistributor(distributorId, merkleRoot, total, claimDeadline)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        distributor: distributor,
+        authority: this.provider.wallet.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return { distributor, txSignature };
+  }
+
+  async claim(
+    mint: PublicKey,
+    distributor: PublicKey,
+    tokenAccount: PublicKey,
+    index: anchor.BN,
+    amount: anchor.BN,
+    proof: number[][]
+  ): Promise<string> {
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .claim(index, amount, proof)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        distributor: distributor,
+        claimStatus: this.findClaimStatus(distributor, index),
+        tokenAccount: tokenAccount,
+        decayPool: decayPool,
+        claimant: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async clawbackDistributor(
+    mint: PublicKey,
+    distributor: PublicKey,
+    tokenAccount: PublicKey
+  ): Promise<string> {
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .clawbackDistributor()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        distributor: distributor,
+        tokenAccount: tokenAccount,
+        decayPool: decayPool,
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  findSnapshot(mint: PublicKey, snapshotId: anchor.BN): PublicKey {
+    const [snapshot] = PublicKey.findProgramAddressSync(
+      [Buffer.from('snapshot'), mint.toBuffer(), snapshotId.toArrayLike(Buffer, 'le', 8)],
+      this.program.programId
+    );
+    return snapshot;
+  }
+
+  findBalanceRecord(snapshot: PublicKey, tokenAccount: PublicKey): PublicKey {
+    const [balanceRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from('balance_record'), snapshot.toBuffer(), tokenAccount.toBuffer()],
+      this.program.programId
+    );
+    return balanceRecord;
+  }
+
+  async openSnapshot(
+    mint: PublicKey,
+    snapshotId: anchor.BN,
+    snapshotTime: anchor.BN,
+    recordDeadline: anchor.BN
+  ): Promise<{ snapshot: PublicKey; txSignature: string }> {
+    const snapshot = this.findSnapshot(mint, snapshotId);
+
+    const txSignature = await this.program.methods
+      .openSnapshot(snapshotId, snapshotTime, recordDeadline)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        snapshot: snapshot,
+        authority: this.provider.wallet.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return { snapshot, txSignature };
+  }
+
+  async recordSnapshotBalance(
+    mint: PublicKey,
+    snapshot: PublicKey,
+    tokenAccount: PublicKey
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .recordSnapshotBalance()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        snapshot: snapshot,
+        tokenAccount: tokenAccount,
+        owner: await this.findTokenAccountOwner(tokenAccount),
+        balanceRecord: this.findBalanceRecord(snapshot, tokenAccount),
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async getSnapshotBalance(
+    snapshot: PublicKey,
+    tokenAccount: PublicKey
+  ): Promise<{ owner: PublicKey; balance: anchor.BN; snapshotTime: anchor.BN; recordedTotal: anchor.BN }> {
+    return await this.program.methods
+      .getSnapshotBalance()
+      .accounts({
+        snapshot: snapshot,
+        balanceRecord: this.findBalanceRecord(snapshot, tokenAccount),
+      })
+      .view();
+  }
+
+  findEscrow(mint: PublicKey, depositor: PublicKey, escrowId: anchor.BN): PublicKey {
+    const [escrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from('escrow'), mint.toBuffer(), depositor.toBuffer(), escrowId.toArrayLike(Buffer, 'le', 8)],
+      this.program.programId
+    );
+    return escrow;
+  }
+
+  findEscrowVault(escrow: PublicKey): PublicKey {
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from('escrow_vault'), escrow.toBuffer()],
+      this.program.programId
+    );
+    return vault;
+  }
+
+  async createEscrow(
+    mint: PublicKey,
+    depositorTokenAccount: PublicKey,
+    escrowId: anchor.BN,
+    amount: anchor.BN,
+    beneficiary: PublicKey,
+    unlockTime: anchor.BN,
+    arbiter: PublicKey | null,
+    decayMode: EscrowDecayMode
+  ): Promise<{ escrow: PublicKey; vault: PublicKey; txSignature: string }> {
+    const escrow = this.findEscrow(mint, this.provider.wallet.publicKey, escrowId);
+    const vault = this.findEscrowVault(escrow);
+    const [vaultDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), vault.toBuffer()],
+      this.program.programId
+    );
+    const [depositorDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), depositorTokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const txSignature = await this.program.methods
+      .createEscrow(escrowId, amount, beneficiary, unlockTime, arbiter, decayMode)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        escrow: escrow,
+        vault: vault,
+        vaultDecayPool: vaultDecayPool,
+        depositorTokenAccount: depositorTokenAccount,
+        depositorDecayPool: depositorDecayPool,
+        depositor: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return { escrow, vault, txSignature };
+  }
+
+  private async escrowAccounts(mint: PublicKey, escrow: PublicKey, tokenAccount: PublicKey, refund: boolean) {
+    const vault = this.findEscrowVault(escrow);
+    const { decayMode, depositorTokenAccount } = await this.program.account.escrow.fetch(escrow);
+    const [vaultDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), vault.toBuffer()],
+      this.program.programId
+    );
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    return {
+      mint: mint,
+      chronoExtension: this.findChronoExtension(mint),
+      schedule: await this.resolveSchedule(mint),
+      tierTable: await this.resolveTierTable(mint),
+      escrow: escrow,
+      exemptionRegistry: await this.resolveExemptionRegistry(mint),
+      vault: vault,
+      vaultDecayPool: vaultDecayPool,
+      tokenAccount: tokenAccount,
+      decayPool: decayPool,
+      owner: await this.findTokenAccountOwner(tokenAccount),
+      // A shared vault's decay goes to the depositor, on refund through `decayPool`
+      depositorDecayPool: 'shared' in decayMode && !refund
+        ? PublicKey.findProgramAddressSync(
+            [Buffer.from("decay_pool"), depositorTokenAccount.toBuffer()],
+            this.program.programId
+          )[0]
+        : null,
+    };
+  }
+
+  async releaseEscrow(
+    mint: PublicKey,
+    escrow: PublicKey,
+    beneficiaryTokenAccount: PublicKey,
+    authority?: Keypair
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .releaseEscrow()
+      .accounts({
+        ...(await this.escrowAccounts(mint, escrow, beneficiaryTokenAccount, false)),
+        authority: authority ? authority.publicKey : this.provider.wallet.publicKey,
+      })
+      .signers(authority ? [authority] : [])
+      .rpc();
+
+    return tx;
+  }
+
+  async refundEscrow(
+    mint: PublicKey,
+    escrow: PublicKey,
+    depositorTokenAccount: PublicKey,
+    authority?: Keypair
+  ): Promise<string> {
+    const tx = await this.program.methods
+      .refundEscrow()
+      .accounts({
+        ...(await this.escrowAccounts(mint, escrow, depositorTokenAccount, true)),
+        authority: authority ? authority.publicKey : this.provider.wallet.publicKey,
+      })
+      .signers(authority ? [authority] : [])
+      .rpc();
+
+    return tx;
+  }
+
+  findStakePool(mint: PublicKey): PublicKey {
+    const [stakePool] = PublicKey.findProgramAddressSync(
+      [Buffer.from('stake_pool'), mint.toBuffer()],
+      this.program.programId
+    );
+    return stakePool;
+  }
+
+  findStakeVault(stakePool: PublicKey): PublicKey {
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from('stake_vault'), stakePool.toBuffer()],
+      this.program.programId
+    );
+    return vault;
+  }
+
+  findStakePosition(stakePool: PublicKey, tokenAccount: PublicKey): PublicKey {
+    const [stakePosition] = PublicKey.findProgramAddressSync(
+      [Buffer.from('stake_position'), stakePool.toBuffer(), tokenAccount.toBuffer()],
+      this.program.programId
+    );
+    return stakePosition;
+  }
+
+  // Harvested decay is released to stakers over `rewardDuration`, in the mint's time base
+  async initializeStakePool(mint: PublicKey, rewardDuration: anchor.BN): Promise<string> {
+    const stakePool = this.findStakePool(mint);
+    const vault = this.findStakeVault(stakePool);
+    const [vaultDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), vault.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .initializeStakePool(rewardDuration)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        stakePool: stakePool,
+        vault: vault,
+        vaultDecayPool: vaultDecayPool,
+        authority: this.provider.wallet.publicKey,
+        payer: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async openStakePosition(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+    const stakePool = this.findStakePool(mint);
+
+    const tx = await this.program.methods
+      .openStakePosition()
+      .accounts({
+        stakePool: stakePool,
+        tokenAccount: tokenAccount,
+        stakePosition: this.findStakePosition(stakePool, tokenAccount),
+        owner: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  private async stakeAccounts(mint: PublicKey, tokenAccount: PublicKey) {
+    const stakePool = this.findStakePool(mint);
+    const vault = this.findStakeVault(stakePool);
+    const [vaultDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), vault.toBuffer()],
+      this.program.programId
+    );
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    return {
+      mint: mint,
+      chronoExtension: this.findChronoExtension(mint),
+      schedule: await this.resolveSchedule(mint),
+      tierTable: await this.resolveTierTable(mint),
+      exemptionRegistry: await this.resolveExemptionRegistry(mint),
+      stakePool: stakePool,
+      vault: vault,
+      vaultDecayPool: vaultDecayPool,
+      stakePosition: this.findStakePosition(stakePool, tokenAccount),
+      tokenAccount: tokenAccount,
+      decayPool: decayPool,
+      owner: this.provider.wallet.publicKey,
+    };
+  }
+
+  async stake(mint: PublicKey, tokenAccount: PublicKey, amount: anchor.BN): Promise<string> {
+    const tx = await this.program.methods
+      .stake(amount)
+      .accounts(await this.stakeAccounts(mint, tokenAccount))
+      .rpc();
+
+    return tx;
+  }
+
+  async unstake(mint: PublicKey, tokenAccount: PublicKey, amount: anchor.BN): Promise<string> {
+    const tx = await this.program.methods
+      .unstake(amount)
+      .accounts(await this.stakeAccounts(mint, tokenAccount))
+      .rpc();
+
+    return tx;
+  }
+
+  async claimStakeRewards(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .claimStakeRewards()
+      .accounts(await this.stakeAccounts(mint, tokenAccount))
+      .rpc();
+
+    return tx;
+  }
+
+  async harvestDecay(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+    const [decayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), tokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .harvestDecay()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        stakePool: this.findStakePool(mint),
+        tokenAccount: tokenAccount,
+        decayPool: decayPool,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  // Only decay pools of accounts their owner opted in can be harvested
+  async addHarvestSource(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .addHarvestSource()
+      .accounts({
+        mint: mint,
+        stakePool: this.findStakePool(mint),
+        tokenAccount: tokenAccount,
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async removeHarvestSource(mint: PublicKey, tokenAccount: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .removeHarvestSource()
+      .accounts({
+        mint: mint,
+        stakePool: this.findStakePool(mint),
+        tokenAccount: tokenAccount,
+        authority: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  findStream(mint: PublicKey, sender: PublicKey, streamId: anchor.BN): PublicKey {
+    const [stream] = PublicKey.findProgramAddressSync(
+      [Buffer.from('stream'), mint.toBuffer(), sender.toBuffer(), streamId.toArrayLike(Buffer, 'le', 8)],
+      this.program.programId
+    );
+    return stream;
+  }
+
+  async createStream(
+    mint: PublicKey,
+    senderTokenAccount: PublicKey,
+    recipientTokenAccount: PublicKey,
+    streamId: anchor.BN,
+    ratePerUnit: anchor.BN,
+    startTime: anchor.BN,
+    endTime: anchor.BN
+  ): Promise<{ stream: PublicKey; txSignature: string }> {
+    const stream = this.findStream(mint, this.provider.wallet.publicKey, streamId);
+    const [senderDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), senderTokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const txSignature = await this.program.methods
+      .createStream(streamId, ratePerUnit, startTime, endTime)
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        stream: stream,
+        senderTokenAccount: senderTokenAccount,
+        senderDecayPool: senderDecayPool,
+        recipientTokenAccount: recipientTokenAccount,
+        sender: this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return { stream, txSignature };
+  }
+
+  async withdrawStream(mint: PublicKey, stream: PublicKey): Promise<string> {
+    const { recipientTokenAccount } = await this.program.account.stream.fetch(stream);
+    const [recipientDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), recipientTokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .withdrawStream()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        stream: stream,
+        recipientTokenAccount: recipientTokenAccount,
+        recipientDecayPool: recipientDecayPool,
+        recipientOwner: await this.findTokenAccountOwner(recipientTokenAccount),
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async cancelStream(mint: PublicKey, stream: PublicKey, authority?: Keypair): Promise<string> {
+    const { senderTokenAccount, recipientTokenAccount } = await this.program.account.stream.fetch(stream);
+    const [senderDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), senderTokenAccount.toBuffer()],
+      this.program.programId
+    );
+    const [recipientDecayPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("decay_pool"), recipientTokenAccount.toBuffer()],
+      this.program.programId
+    );
+
+    const tx = await this.program.methods
+      .cancelStream()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        stream: stream,
+        senderTokenAccount: senderTokenAccount,
+        senderDecayPool: senderDecayPool,
+        recipientTokenAccount: recipientTokenAccount,
+        recipientDecayPool: recipientDecayPool,
+        senderOwner: await this.findTokenAccountOwner(senderTokenAccount),
+        recipientOwner: await this.findTokenAccountOwner(recipientTokenAccount),
+        authority: authority ? authority.publicKey : this.provider.wallet.publicKey,
+      })
+      .signers(authority ? [authority] : [])
+      .rpc();
+
+    return tx;
+  }
+
+  // Streams paying into or out of the account are included in its balance
+  async getBalance(
+    mint: PublicKey,
+    tokenAccount: PublicKey
+  ): Promise<{ balance: anchor.BN; vested: anchor.BN; locked: anchor.BN; streamed: anchor.BN }> {
+    return await this.program.methods
+      .getBalance()
+      .accounts({
+        mint: mint,
+        chronoExtension: this.findChronoExtension(mint),
+        schedule: await this.resolveSchedule(mint),
+        tierTable: await this.resolveTierTable(mint),
+        exemptionRegistry: await this.resolveExemptionRegistry(mint),
+        tokenAccount: tokenAccount,
+        owner: await this.findTokenAccountOwner(tokenAccount),
+      })
+      .view();
+  }
+
+  // Current time in the mint's time base
+  async currentTime(timeBase: TimeBase): Promise<anchor.BN> {
+    if ('slot' in timeBase) {
+      return new anchor.BN(await this.provider.connection.getSlot());
+    } else if ('epoch' in timeBase) {
+      const { epoch } = await this.provider.connection.getEpochInfo();
+      return new anchor.BN(epoch);
+    }
+    return new anchor.BN(Math.floor(Date.now() / 1000));
+  }
+
+  ///get balance
+  async getTokenAccountBalance(tokenAccountAddress: PublicKey): Promise<anchor.BN> {
+    const tokenAccount = await this.program.account.tokenAccount.fetch(tokenAccountAddress);
+
+    // Fetch the mint account
+    const mint = await this.program.account.mint.fetch(tokenAccount.mint);
+
+    // Get the ChronoExtension PDA
+    const [chronoExtensionPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('chrono_extension'), tokenAccount.mint.toBuffer()],
+      this.program.programId
+    );
+
+    // Fetch the ChronoExtension account
+    let chronoExtension: ChronoExtension | null = null;
+    try {
+      chronoExtension = await this.program.account.chronoExtension.fetch(chronoExtensionPDA);
+    } catch (error) {
+      console.error('Failed to fetch ChronoExtension:', error);
+      // If there's no chrono extension, return the last balance snapshot
+      return tokenAccount.lastBalanceSnapshot;
+    }
+
+    if (!chronoExtension) {
+      // If there's no chrono extension, return the last balance snapshot
+      return tokenAccount.lastBalanceSnapshot;
+    }
+
+    const currentTime = await this.currentTime(chronoExtension.timeBase);
+
+    // Mirrors evaluate_balance, what outgoing streams still owe is held flat
+    const { streams, lastUpdateTime } = tokenAccount;
+    const zero = new anchor.BN(0);
+    const committed = streams
+      .filter((leg) => leg.outgoing)
+      .reduce((sum, leg) => sum.add(this.streamedBetween(leg, lastUpdateTime, leg.endTime)), zero);
+    const streamedIn = streams
+      .filter((leg) => !leg.outgoing)
+      .reduce((sum, leg) => sum.add(this.streamedBetween(leg, lastUpdateTime, currentTime)), zero);
+    const streamedOut = streams
+      .filter((leg) => leg.outgoing)
+      .reduce((sum, leg) => sum.add(this.streamedBetween(leg, lastUpdateTime, currentTime)), zero);
+
+    const evaluatedBalance = this.calculateBalance(
+      tokenAccount.lastBalanceSnapshot.sub(committed),
+      tokenAccount.currentChronoEquation,
+      chronoExtension.equationParams,
+      currentTime,
+      tokenAccount.curveAge
+    ).add(committed);
+    return this.boundBalance(tokenAccount.lastBalanceSnapshot, evaluatedBalance, chronoExtension.equationParams)
+      .add(streamedIn)
+      .sub(streamedOut);
   }
 }